@@ -0,0 +1,38 @@
+//! Compares `Guideline::from_id`, a binary search over a sorted table, with the linear scan over
+//! `Guideline::all` it replaced.
+
+use std::hint::black_box;
+
+use api_guidelines::Guideline;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn linear_from_id(id: &str) -> Option<Guideline> {
+    Guideline::all()
+        .iter()
+        .copied()
+        .find(|g| g.id().eq_ignore_ascii_case(id))
+}
+
+fn from_id(c: &mut Criterion) {
+    let codes: Vec<&str> = Guideline::all().iter().map(|g| g.id()).collect();
+
+    let mut group = c.benchmark_group("from_id");
+    group.bench_function("linear", |b| {
+        b.iter(|| {
+            for code in &codes {
+                black_box(linear_from_id(black_box(code)));
+            }
+        })
+    });
+    group.bench_function("binary_search", |b| {
+        b.iter(|| {
+            for code in &codes {
+                black_box(Guideline::from_id(black_box(code)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, from_id);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+use crate::{Category, Guideline, GuidelineSet, Iter};
+
+/// The catalog as a value, for code that takes its guidelines as a dependency instead of calling
+/// [`Guideline::all`] and friends directly.
+///
+/// [`Catalog::official`] holds every guideline; a catalog built from a [`GuidelineSet`] holds only
+/// those, which lets tests hand a trimmed-down catalog to the code under test. A catalog is `Copy`
+/// and eight bytes large.
+///
+/// ```rust
+/// use api_guidelines::{Catalog, Category, Guideline, GuidelineSet, Naming};
+///
+/// let official = Catalog::official();
+/// assert_eq!(official.all().len(), Guideline::COUNT);
+/// assert_eq!(official.from_id("c-case"), Some(Naming::C_CASE.into()));
+///
+/// let custom = Catalog::from(GuidelineSet::from_iter([Naming::C_CASE, Naming::C_GETTER]));
+/// assert_eq!(custom.by_category(Category::Naming).len(), 2);
+/// assert_eq!(custom.from_id("C-ITER"), None);
+/// assert_eq!(custom.search("getter"), [Guideline::from(Naming::C_GETTER)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Catalog {
+    guidelines: GuidelineSet,
+}
+
+impl Catalog {
+    /// The catalog of every guideline in the official checklist.
+    pub fn official() -> Catalog {
+        Catalog {
+            guidelines: Guideline::all().iter().copied().collect(),
+        }
+    }
+
+    /// The guidelines in the catalog, in official order.
+    pub fn all(&self) -> Iter {
+        self.guidelines.iter()
+    }
+
+    /// Returns whether the guideline is in the catalog.
+    pub fn contains(&self, guideline: impl Into<Guideline>) -> bool {
+        self.guidelines.contains(guideline)
+    }
+
+    /// Looks up a guideline like [`Guideline::from_id`], returning `None` for guidelines outside
+    /// the catalog.
+    pub fn from_id(&self, id: &str) -> Option<Guideline> {
+        Guideline::from_id(id).filter(|&guideline| self.contains(guideline))
+    }
+
+    /// The guidelines whose code or title contains `keyword`, ignoring case, in official order.
+    pub fn search(&self, keyword: &str) -> Vec<Guideline> {
+        let keyword = keyword.to_lowercase();
+        self.all()
+            .filter(|g| {
+                g.id().to_lowercase().contains(&keyword)
+                    || g.title().to_lowercase().contains(&keyword)
+            })
+            .collect()
+    }
+
+    /// The guidelines of `category` in the catalog, in official order.
+    pub fn by_category(&self, category: Category) -> Vec<Guideline> {
+        self.all().filter(|g| g.category() == category).collect()
+    }
+}
+
+impl Default for Catalog {
+    /// The [official](Catalog::official) catalog.
+    fn default() -> Catalog {
+        Catalog::official()
+    }
+}
+
+impl From<GuidelineSet> for Catalog {
+    fn from(guidelines: GuidelineSet) -> Catalog {
+        Catalog { guidelines }
+    }
+}
@@ -0,0 +1,3 @@
+//! Helpers for applying the [`Debuggability`] guidelines.
+
+pub use crate::Debuggability;
@@ -0,0 +1,119 @@
+//! Helpers for applying the [`Dependability`] guidelines.
+
+pub use crate::Dependability;
+
+use crate::Guideline;
+
+/// How a function should enforce the validity of its arguments, in the order of preference given by
+/// [C-VALIDATE](crate::Dependability::C_VALIDATE).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationStrategy {
+    /// Choose an argument type that rules out bad inputs, e.g. a newtype wrapper.
+    Static,
+    /// Validate the input at run time and report failures through `Result`, `Option` or a panic.
+    Dynamic,
+    /// Validate at run time and add `_unchecked` siblings that skip the check, named with
+    /// [`unchecked_name`].
+    DynamicWithUncheckedOptOut,
+}
+
+/// Picks the most preferred [`ValidationStrategy`] for a function's arguments.
+///
+/// | `static_possible` | `expensive` | Strategy                       |
+/// |-------------------|-------------|--------------------------------|
+/// | `true`            | any         | [`Static`]                     |
+/// | `false`           | `false`     | [`Dynamic`]                    |
+/// | `false`           | `true`      | [`DynamicWithUncheckedOptOut`] |
+///
+/// Static enforcement pushes the cost to the boundaries, so it wins whenever the invariant can be
+/// expressed in a type. An expensive check keeps running by default, including in release builds,
+/// while `_unchecked` siblings let callers that have already validated their input skip it. A
+/// `debug_assert!` is not offered, since it would silently drop the check from release builds.
+///
+/// [`Static`]: ValidationStrategy::Static
+/// [`Dynamic`]: ValidationStrategy::Dynamic
+/// [`DynamicWithUncheckedOptOut`]: ValidationStrategy::DynamicWithUncheckedOptOut
+///
+/// ```rust
+/// use api_guidelines::dependability::{validation_strategy, ValidationStrategy};
+///
+/// assert_eq!(validation_strategy(true, false), ValidationStrategy::Static);
+/// assert_eq!(validation_strategy(true, true), ValidationStrategy::Static);
+/// assert_eq!(validation_strategy(false, false), ValidationStrategy::Dynamic);
+/// assert_eq!(validation_strategy(false, true), ValidationStrategy::DynamicWithUncheckedOptOut);
+/// ```
+pub fn validation_strategy(static_possible: bool, expensive: bool) -> ValidationStrategy {
+    match (static_possible, expensive) {
+        (true, _) => ValidationStrategy::Static,
+        (false, false) => ValidationStrategy::Dynamic,
+        (false, true) => ValidationStrategy::DynamicWithUncheckedOptOut,
+    }
+}
+
+/// Returns the conventional name of the unchecked opt-out sibling of a validating function.
+///
+/// The `_unchecked` suffix goes before a trailing `_mut`, following `get_unchecked_mut` in the
+/// standard library. Names that already carry the suffix are returned unchanged.
+///
+/// ```rust
+/// use api_guidelines::dependability::unchecked_name;
+///
+/// assert_eq!(unchecked_name("from_utf8"), "from_utf8_unchecked");
+/// assert_eq!(unchecked_name("get"), "get_unchecked");
+/// assert_eq!(unchecked_name("get_mut"), "get_unchecked_mut");
+/// assert_eq!(unchecked_name("get_unchecked"), "get_unchecked");
+/// ```
+pub fn unchecked_name(base: &str) -> String {
+    if is_unchecked(base) {
+        return base.to_owned();
+    }
+    match base.strip_suffix("_mut") {
+        Some(stem) if !stem.is_empty() => format!("{stem}_unchecked_mut"),
+        _ => format!("{base}_unchecked"),
+    }
+}
+
+/// Returns whether `name` follows the `_unchecked` opt-out naming convention.
+///
+/// ```rust
+/// use api_guidelines::dependability::is_unchecked;
+///
+/// assert!(is_unchecked("from_utf8_unchecked"));
+/// assert!(is_unchecked("get_unchecked_mut"));
+/// assert!(!is_unchecked("get"));
+/// assert!(!is_unchecked("unchecked"));
+/// ```
+pub fn is_unchecked(name: &str) -> bool {
+    let stem = name.strip_suffix("_mut").unwrap_or(name);
+    stem.strip_suffix("_unchecked")
+        .is_some_and(|prefix| !prefix.is_empty())
+}
+
+/// Suggested fix for destructors flagged by [`dtor_guidance`].
+pub const TEARDOWN_SUGGESTION: &str = "add a separate teardown method such as `close(self) -> Result<(), E>` \
+     and have `Drop` fall back to it, ignoring or logging any error";
+
+/// Returns the codes of the destructor guidelines that apply to a `Drop` impl, per
+/// [C-DTOR-FAIL](crate::Dependability::C_DTOR_FAIL) and
+/// [C-DTOR-BLOCK](crate::Dependability::C_DTOR_BLOCK).
+///
+/// Both are addressed the same way, described by [`TEARDOWN_SUGGESTION`].
+///
+/// ```rust
+/// use api_guidelines::dependability::dtor_guidance;
+///
+/// assert!(dtor_guidance(false, false).is_empty());
+/// assert_eq!(dtor_guidance(true, false), ["C-DTOR-FAIL"]);
+/// assert_eq!(dtor_guidance(false, true), ["C-DTOR-BLOCK"]);
+/// assert_eq!(dtor_guidance(true, true), ["C-DTOR-FAIL", "C-DTOR-BLOCK"]);
+/// ```
+pub fn dtor_guidance(drop_may_fail: bool, drop_may_block: bool) -> Vec<&'static str> {
+    [
+        (drop_may_fail, Dependability::C_DTOR_FAIL),
+        (drop_may_block, Dependability::C_DTOR_BLOCK),
+    ]
+    .into_iter()
+    .filter(|&(applies, _)| applies)
+    .map(|(_, guideline)| Guideline::from(guideline).id())
+    .collect()
+}
@@ -0,0 +1,647 @@
+//! Helpers for applying the [`Documentation`] guidelines.
+
+pub use crate::Documentation;
+
+use crate::Violation;
+
+/// Recommends `#[doc(hidden)]` on an impl that references a type users can never name or
+/// construct, per [C-HIDDEN](crate::Documentation::C_HIDDEN).
+///
+/// The typical case is `impl From<PrivateError> for PublicError`, which the `?` operator needs but
+/// which only clutters the public documentation. `impl_involves_private_type` tells whether the
+/// impl touches such a type.
+///
+/// ```rust
+/// use api_guidelines::documentation::suggest_hidden;
+///
+/// let hint = suggest_hidden(true).unwrap();
+/// assert!(hint.contains("#[doc(hidden)]"));
+///
+/// assert_eq!(suggest_hidden(false), None);
+/// ```
+pub fn suggest_hidden(impl_involves_private_type: bool) -> Option<String> {
+    impl_involves_private_type.then(|| {
+        "annotate the impl with `#[doc(hidden)]`; it involves a type users can never construct, so documenting it is only noise (C-HIDDEN)".to_owned()
+    })
+}
+
+/// The `[package]` fields [C-METADATA](crate::Documentation::C_METADATA) requires.
+const REQUIRED_METADATA: &[&str] = &[
+    "authors",
+    "description",
+    "license",
+    "repository",
+    "keywords",
+    "categories",
+];
+
+/// The result of [`check_metadata`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct MetadataReport {
+    /// Required `[package]` keys that are missing, in the order C-METADATA lists them.
+    pub missing: Vec<&'static str>,
+    /// Problems with the values of present keys, such as a redundant `homepage`.
+    pub warnings: Vec<String>,
+}
+
+/// Checks the `[package]` keys of a manifest against
+/// [C-METADATA](crate::Documentation::C_METADATA).
+///
+/// Each entry of `present_keys` is either a bare key or a `key = "value"` line. When values are
+/// given and `homepage` equals `repository` or `documentation`, a warning is reported since the
+/// homepage should only be set for a dedicated website. The optional `documentation` and
+/// `homepage` fields are never reported missing.
+///
+/// ```rust
+/// use api_guidelines::documentation::check_metadata;
+///
+/// let report = check_metadata(&["authors", "description", "license", "repository"]);
+/// assert_eq!(report.missing, ["keywords", "categories"]);
+/// assert!(report.warnings.is_empty());
+///
+/// let report = check_metadata(&[
+///     "authors", "description", "license", "keywords", "categories",
+///     r#"repository = "https://github.com/rust-lang/api-guidelines""#,
+///     r#"homepage = "https://github.com/rust-lang/api-guidelines""#,
+/// ]);
+/// assert!(report.missing.is_empty());
+/// assert_eq!(report.warnings.len(), 1);
+/// assert!(report.warnings[0].contains("homepage"));
+/// ```
+pub fn check_metadata(present_keys: &[&str]) -> MetadataReport {
+    let entries: Vec<(&str, Option<&str>)> = present_keys
+        .iter()
+        .map(|entry| match entry.split_once('=') {
+            Some((key, value)) => (key.trim(), Some(value.trim().trim_matches('"'))),
+            None => (entry.trim(), None),
+        })
+        .collect();
+    let value_of = |wanted: &str| {
+        entries
+            .iter()
+            .find(|(key, _)| *key == wanted)
+            .and_then(|(_, value)| *value)
+    };
+
+    let missing = REQUIRED_METADATA
+        .iter()
+        .filter(|required| !entries.iter().any(|(key, _)| key == *required))
+        .copied()
+        .collect();
+    let mut warnings = Vec::new();
+    if let Some(homepage) = value_of("homepage")
+        && [value_of("repository"), value_of("documentation")].contains(&Some(homepage))
+    {
+        warnings.push(
+            "`homepage` duplicates `repository` or `documentation`; only set it for a dedicated \
+             website (C-METADATA)"
+                .to_owned(),
+        );
+    }
+    MetadataReport { missing, warnings }
+}
+
+/// An error handling pattern in a rustdoc example that should use `?` instead, see
+/// [`check_example_error_handling`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExampleLint {
+    /// The 1-based line of the example the pattern is on.
+    pub line: usize,
+    /// The offending pattern, `.unwrap()`, `try!(` or `.expect(`.
+    pub pattern: &'static str,
+    /// `.expect(` at least explains the failure, so it is only a softer warning.
+    pub soft: bool,
+    pub message: String,
+}
+
+impl From<ExampleLint> for Violation {
+    fn from(lint: ExampleLint) -> Violation {
+        Violation::from_message(
+            Documentation::C_QUESTION_MARK,
+            &lint.message,
+            Some(format!("line {} of the example", lint.line)),
+        )
+    }
+}
+
+/// Scans a rustdoc example for `.unwrap()` and `try!(` per
+/// [C-QUESTION-MARK](crate::Documentation::C_QUESTION_MARK), returning one lint per occurrence.
+///
+/// Examples get copied into user code, so they should show `?` with a `main` returning `Result`,
+/// hidden behind `#` lines where it would distract. `.expect(` is reported as well, but marked
+/// [`soft`](ExampleLint::soft). Lines that are entirely comments are skipped.
+///
+/// ```rust
+/// use api_guidelines::documentation::check_example_error_handling;
+///
+/// let lints = check_example_error_handling("let file = File::open(path).unwrap();\nlet n = x.parse::<u8>().expect(\"a number\");");
+/// assert_eq!(lints.len(), 2);
+/// assert_eq!((lints[0].line, lints[0].pattern, lints[0].soft), (1, ".unwrap()", false));
+/// assert_eq!((lints[1].line, lints[1].pattern, lints[1].soft), (2, ".expect(", true));
+///
+/// let clean = "# fn main() -> Result<(), std::io::Error> {\nlet file = File::open(path)?;\n# Ok(())\n# }";
+/// assert!(check_example_error_handling(clean).is_empty());
+/// ```
+pub fn check_example_error_handling(example_src: &str) -> Vec<ExampleLint> {
+    const PATTERNS: &[(&str, bool)] = &[(".unwrap()", false), ("try!(", false), (".expect(", true)];
+
+    let mut lints = Vec::new();
+    for (index, line) in example_src.lines().enumerate() {
+        let code = line.trim_start().trim_start_matches('#').trim_start();
+        if code.starts_with("//") {
+            continue;
+        }
+        for &(pattern, soft) in PATTERNS {
+            for _ in code.matches(pattern) {
+                let message = if soft {
+                    format!(
+                        "`{pattern}` in an example; `?` with a `Result`-returning `main` is preferred (C-QUESTION-MARK)"
+                    )
+                } else {
+                    format!(
+                        "replace `{pattern}` with `?` and a `Result`-returning `main` (C-QUESTION-MARK)"
+                    )
+                };
+                lints.push(ExampleLint {
+                    line: index + 1,
+                    pattern,
+                    soft,
+                    message,
+                });
+            }
+        }
+    }
+    lints
+}
+
+/// Reports the failure sections [C-FAILURE](crate::Documentation::C_FAILURE) requires but `doc`
+/// lacks: `# Errors` for functions returning `Result`, `# Panics` for functions that can panic and
+/// `# Safety` for `unsafe` functions.
+///
+/// Headings of any level are accepted, so `## Errors` counts too.
+///
+/// ```rust
+/// use api_guidelines::documentation::check_failure_sections;
+///
+/// assert_eq!(check_failure_sections(true, false, false, "Opens a file."), ["# Errors"]);
+/// assert_eq!(check_failure_sections(false, true, false, "Indexes."), ["# Panics"]);
+/// assert_eq!(check_failure_sections(false, false, true, "Reads."), ["# Safety"]);
+///
+/// let doc = "Opens a file.\n\n# Errors\n\nFails if the file does not exist.";
+/// assert!(check_failure_sections(true, false, false, doc).is_empty());
+/// ```
+pub fn check_failure_sections(
+    returns_result: bool,
+    can_panic: bool,
+    is_unsafe: bool,
+    doc: &str,
+) -> Vec<&'static str> {
+    let has_section = |name: &str| {
+        doc.lines().any(|line| {
+            let line = line.trim();
+            line.starts_with('#') && line.trim_start_matches('#').trim() == name
+        })
+    };
+    [
+        (returns_result, "Errors", "# Errors"),
+        (can_panic, "Panics", "# Panics"),
+        (is_unsafe, "Safety", "# Safety"),
+    ]
+    .into_iter()
+    .filter(|&(required, name, _)| required && !has_section(name))
+    .map(|(_, _, heading)| heading)
+    .collect()
+}
+
+/// Returns whether a doc comment contains a Rust code example, as
+/// [C-EXAMPLE](crate::Documentation::C_EXAMPLE) expects of every public item.
+///
+/// Bare fences and fences tagged `rust` or with rustdoc attributes such as `no_run` or `ignore`
+/// count, indented or not. Fences for other languages, like `text` or `toml`, do not.
+///
+/// ```rust
+/// use api_guidelines::documentation::has_example;
+///
+/// assert!(has_example("Adds one.\n\n```rust\nassert_eq!(add_one(1), 2);\n```"));
+/// assert!(has_example("Connects.\n\n  ```no_run\n  let c = connect()?;\n  ```"));
+/// assert!(has_example("```\nlet x = 1;\n```"));
+///
+/// assert!(!has_example("Adds one to the number given."));
+/// assert!(!has_example("```toml\n[dependencies]\n```"));
+/// ```
+pub fn has_example(doc: &str) -> bool {
+    !rust_examples(doc).is_empty()
+}
+
+/// Returns the code of each Rust example in a doc comment, as recognized by [`has_example`]. An
+/// unclosed fence runs to the end of the doc.
+pub(crate) fn rust_examples(doc: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    // `Some(is_rust)` while inside a fence.
+    let mut fence = None;
+    let mut code = String::new();
+    for line in doc.lines() {
+        let trimmed = line.trim();
+        let Some(info) = trimmed
+            .strip_prefix("```")
+            .or_else(|| trimmed.strip_prefix("~~~"))
+        else {
+            if fence == Some(true) {
+                code.push_str(line.trim_start());
+                code.push('\n');
+            }
+            continue;
+        };
+        match fence.take() {
+            Some(true) => examples.push(std::mem::take(&mut code)),
+            Some(false) => {}
+            None => {
+                let is_rust = info
+                    .split([',', ' '])
+                    .filter(|attr| !attr.is_empty())
+                    .all(|attr| {
+                        matches!(
+                            attr,
+                            "rust"
+                                | "no_run"
+                                | "ignore"
+                                | "should_panic"
+                                | "compile_fail"
+                                | "test_harness"
+                        ) || attr.starts_with("edition")
+                    });
+                fence = Some(is_rust);
+            }
+        }
+    }
+    if fence == Some(true) {
+        examples.push(code);
+    }
+    examples
+}
+
+/// Like [`has_example`], but returns a lint message naming `item_name` when the example is missing.
+///
+/// ```rust
+/// use api_guidelines::documentation::check_example;
+///
+/// assert!(check_example("add_one", "Adds one.").unwrap().contains("`add_one`"));
+/// assert_eq!(check_example("add_one", "```\nadd_one(1);\n```"), None);
+/// ```
+pub fn check_example(item_name: &str, doc: &str) -> Option<String> {
+    (!has_example(doc))
+        .then(|| format!("`{item_name}` has no example; show how and why to use it (C-EXAMPLE)"))
+}
+
+/// A missing hyperlink in a doc comment, see [`check_links`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LinkLint {
+    /// A reference link like `` [`Foo`] `` without a matching `` [`Foo`]: target `` definition.
+    Dangling { reference: String },
+    /// A type or function mentioned in backticks that is never linked.
+    Unlinked { name: String },
+}
+
+impl From<LinkLint> for Violation {
+    fn from(lint: LinkLint) -> Violation {
+        let (message, span_hint) = match lint {
+            LinkLint::Dangling { reference } => (
+                format!("[{reference}] has no matching link definition"),
+                reference,
+            ),
+            LinkLint::Unlinked { name } => (format!("link `{name}` to its documentation"), name),
+        };
+        Violation {
+            guideline: Documentation::C_LINK.into(),
+            message,
+            suggestion: None,
+            span_hint: Some(span_hint),
+        }
+    }
+}
+
+/// Finds missing links in a doc comment, per [C-LINK](crate::Documentation::C_LINK).
+///
+/// Shortcut links to a backticked path, like `` [`Foo`] `` or `` [`io::Error`] ``, are intra-doc
+/// links that rustdoc resolves itself. Other reference links, like `[the book]` or
+/// `` [the foo][`Foo`] ``, need a matching `[label]: target` definition elsewhere in the doc.
+/// Backticked names that look like types (`Foo`, `io::Error`) or function calls (`foo()`) are
+/// reported once if no link mentions them, except for prelude items such as `None` or `String`.
+/// Code blocks are skipped.
+///
+/// ```rust
+/// use api_guidelines::documentation::{check_links, LinkLint};
+///
+/// let doc = "Returns a [`Widget`] or `None`, see [`io::Error`] and [`Widget::new()`].";
+/// assert!(check_links(doc).is_empty());
+///
+/// let doc = "Returns a [`Widget`].\n\n[`Widget`]: crate::Widget";
+/// assert!(check_links(doc).is_empty());
+///
+/// let doc = "Returns a [gadget][`Gadget`], see also `Widget` and [the book].";
+/// assert_eq!(
+///     check_links(doc),
+///     [
+///         LinkLint::Dangling { reference: "`Gadget`".to_owned() },
+///         LinkLint::Dangling { reference: "the book".to_owned() },
+///         LinkLint::Unlinked { name: "Widget".to_owned() },
+///     ],
+/// );
+/// ```
+pub fn check_links(doc: &str) -> Vec<LinkLint> {
+    let mut in_fence = false;
+    let prose: Vec<&str> = doc
+        .lines()
+        .filter(|line| {
+            let fence = line.trim_start().starts_with("```");
+            in_fence ^= fence;
+            !in_fence && !fence
+        })
+        .collect();
+
+    let mut definitions = Vec::new();
+    let mut references = Vec::new();
+    let mut code_spans = Vec::new();
+    for line in &prose {
+        if let Some(rest) = line.trim_start().strip_prefix('[')
+            && let Some((label, _)) = rest.split_once("]:")
+        {
+            definitions.push(label.to_lowercase());
+            continue;
+        }
+        let mut rest = *line;
+        while let Some(start) = rest.find(['[', '`']) {
+            let is_link = rest[start..].starts_with('[');
+            let close = if is_link { ']' } else { '`' };
+            let Some(len) = rest[start + 1..].find(close) else {
+                break;
+            };
+            let inner = &rest[start + 1..start + 1 + len];
+            rest = &rest[start + len + 2..];
+            if !is_link {
+                code_spans.push(inner);
+                continue;
+            }
+            if rest.starts_with('(') {
+                references.push((inner, None));
+            } else if let Some(label) = rest.strip_prefix('[').and_then(|r| r.split_once(']')) {
+                let target = if label.0.is_empty() { inner } else { label.0 };
+                references.push((inner, Some(target)));
+                references.push((target, None));
+                rest = label.1;
+            } else if is_intra_doc_link(inner) {
+                references.push((inner, None));
+            } else {
+                references.push((inner, Some(inner)));
+            }
+        }
+    }
+
+    let mut lints = Vec::new();
+    for &(_, target) in &references {
+        if let Some(target) = target
+            && !definitions.contains(&target.to_lowercase())
+        {
+            let lint = LinkLint::Dangling {
+                reference: target.to_owned(),
+            };
+            if !lints.contains(&lint) {
+                lints.push(lint);
+            }
+        }
+    }
+    let linked = |name: &str| {
+        references
+            .iter()
+            .any(|(text, _)| text.trim_matches('`') == name)
+    };
+    for name in code_spans {
+        let lint = LinkLint::Unlinked {
+            name: name.to_owned(),
+        };
+        if looks_like_item(name) && !linked(name) && !lints.contains(&lint) {
+            lints.push(lint);
+        }
+    }
+    lints
+}
+
+/// Returns whether a code span names a type, like `Foo` or `io::Error`, or a function call, like
+/// `foo()`.
+fn looks_like_item(code: &str) -> bool {
+    /// Names in the standard prelude, which readers know without a link.
+    const PRELUDE: &[&str] = &[
+        "Option",
+        "Some",
+        "None",
+        "Result",
+        "Ok",
+        "Err",
+        "String",
+        "Vec",
+        "Box",
+        "Self",
+        "Clone",
+        "Copy",
+        "Send",
+        "Sync",
+        "Sized",
+        "Drop",
+        "Default",
+        "Debug",
+        "PartialEq",
+        "Eq",
+        "PartialOrd",
+        "Ord",
+        "From",
+        "Into",
+        "AsRef",
+        "AsMut",
+        "Iterator",
+        "IntoIterator",
+        "Fn",
+        "FnMut",
+        "FnOnce",
+        "ToString",
+        "ToOwned",
+    ];
+
+    match code.strip_suffix("()") {
+        Some(function) => is_path(function),
+        None => {
+            let last = code.rsplit("::").next().unwrap_or(code);
+            is_path(code)
+                && last.starts_with(|c: char| c.is_ascii_uppercase())
+                && !PRELUDE.contains(&code)
+        }
+    }
+}
+
+/// Returns whether a shortcut link's text is a backticked path, like `` `Foo` `` or
+/// `` `Foo::new()` ``, which rustdoc resolves as an intra-doc link.
+fn is_intra_doc_link(text: &str) -> bool {
+    text.strip_prefix('`')
+        .and_then(|text| text.strip_suffix('`'))
+        .is_some_and(|code| is_path(code.strip_suffix("()").unwrap_or(code)))
+}
+
+/// Returns whether `s` is a `::`-separated path of identifiers.
+fn is_path(s: &str) -> bool {
+    !s.is_empty()
+        && s.split("::").all(|segment| {
+            !segment.is_empty() && segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+        })
+}
+
+/// How a signature change affects semver, see [`classify_change`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    /// Requires a major version bump.
+    Breaking,
+    /// Fits in a minor or patch release.
+    NonBreaking,
+    /// The change is beyond what the analysis understands.
+    Unknown,
+}
+
+/// Classifies the change from `old_sig` to `new_sig` for release notes, following the
+/// [RFC 1105](https://github.com/rust-lang/rfcs/blob/master/text/1105-api-evolution.md) rules
+/// referenced by [C-RELNOTES](crate::Documentation::C_RELNOTES).
+///
+/// The analysis is string-based and conservative. An empty `new_sig` means the item was removed,
+/// which is breaking, and an empty `old_sig` means it was added, which is not. Adding a trait bound
+/// or removing a derive is breaking, while adding a derive or loosening a bound is not. Any other
+/// difference is [`Unknown`](ChangeKind::Unknown).
+///
+/// ```rust
+/// use api_guidelines::documentation::{classify_change, ChangeKind};
+///
+/// assert_eq!(
+///     classify_change("pub fn sort<T>(v: &mut [T])", "pub fn sort<T: Ord>(v: &mut [T])"),
+///     ChangeKind::Breaking,
+/// );
+/// assert_eq!(
+///     classify_change(
+///         "#[derive(Debug, PartialEq)] pub struct Version(u32);",
+///         "#[derive(Debug, PartialEq, PartialOrd)] pub struct Version(u32);",
+///     ),
+///     ChangeKind::NonBreaking,
+/// );
+/// assert_eq!(classify_change("pub fn len(&self) -> usize", ""), ChangeKind::Breaking);
+/// assert_eq!(classify_change("pub fn len(&self) -> usize", "pub fn len(&self) -> u64"), ChangeKind::Unknown);
+/// ```
+pub fn classify_change(old_sig: &str, new_sig: &str) -> ChangeKind {
+    match (old_sig.trim().is_empty(), new_sig.trim().is_empty()) {
+        (true, true) => return ChangeKind::NonBreaking,
+        (false, true) => return ChangeKind::Breaking,
+        (true, false) => return ChangeKind::NonBreaking,
+        (false, false) => {}
+    }
+    let (old_derives, old_rest) = split_derives(old_sig);
+    let (new_derives, new_rest) = split_derives(new_sig);
+    let (old_bounds, old_skeleton) = split_bounds(&old_rest);
+    let (new_bounds, new_skeleton) = split_bounds(&new_rest);
+    let squash = |s: &str| s.split_whitespace().collect::<String>();
+    if squash(&old_skeleton) != squash(&new_skeleton) {
+        return ChangeKind::Unknown;
+    }
+    let removed_derive = old_derives.iter().any(|d| !new_derives.contains(d));
+    let added_bound = new_bounds.iter().any(|b| !old_bounds.contains(b));
+    if removed_derive || added_bound {
+        ChangeKind::Breaking
+    } else {
+        ChangeKind::NonBreaking
+    }
+}
+
+/// Removes the `#[derive(..)]` attributes from a signature, returning the derived traits and the
+/// remaining text.
+fn split_derives(sig: &str) -> (Vec<String>, String) {
+    let mut derives = Vec::new();
+    let mut rest = sig.to_owned();
+    while let Some(start) = rest.find("#[derive(") {
+        let Some(len) = rest[start..].find(")]") else {
+            break;
+        };
+        let list = &rest[start + "#[derive(".len()..start + len];
+        derives.extend(
+            list.split(',')
+                .map(|d| d.split_whitespace().collect::<String>())
+                .filter(|d| !d.is_empty()),
+        );
+        rest.replace_range(start..start + len + 2, " ");
+    }
+    (derives, rest)
+}
+
+/// Removes the trait bounds from the generics and `where` clause of a signature, returning each
+/// bound with the parameter it applies to, and the remaining text.
+fn split_bounds(sig: &str) -> (Vec<(String, String)>, String) {
+    let chars: Vec<char> = sig.chars().collect();
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let mut bounds = Vec::new();
+    let mut skeleton = String::new();
+    let (mut angle, mut paren, mut in_where) = (0usize, 0usize, false);
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let prev = i.checked_sub(1).map(|p| chars[p]);
+        let next = chars.get(i + 1).copied();
+        match c {
+            '<' => angle += 1,
+            '>' if !matches!(prev, Some('-' | '=')) => angle = angle.saturating_sub(1),
+            '(' | '[' => paren += 1,
+            ')' | ']' => paren = paren.saturating_sub(1),
+            '{' | ';' if angle == 0 && paren == 0 => in_where = false,
+            'w' if !prev.is_some_and(is_ident)
+                && chars[i..].starts_with(&['w', 'h', 'e', 'r', 'e'])
+                && !chars.get(i + 5).copied().is_some_and(is_ident) =>
+            {
+                in_where = angle == 0 && paren == 0;
+            }
+            ':' if next != Some(':')
+                && prev != Some(':')
+                && paren == 0
+                && (angle > 0 || in_where) =>
+            {
+                let param: String = skeleton
+                    .trim_end()
+                    .chars()
+                    .rev()
+                    .take_while(|&c| is_ident(c) || c == '\'')
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev()
+                    .collect();
+                let mut bound = String::new();
+                let mut depth = 0usize;
+                i += 1;
+                while i < chars.len() {
+                    let c = chars[i];
+                    let prev = chars[i - 1];
+                    match c {
+                        '<' | '(' | '[' => depth += 1,
+                        '>' if prev == '-' => {}
+                        '>' | ')' | ']' if depth > 0 => depth -= 1,
+                        ',' | '>' | '{' | ';' if depth == 0 => break,
+                        '+' if depth == 0 => {
+                            bounds.push((param.clone(), bound.split_whitespace().collect()));
+                            bound.clear();
+                            i += 1;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    bound.push(c);
+                    i += 1;
+                }
+                bounds.push((param, bound.split_whitespace().collect()));
+                continue;
+            }
+            _ => {}
+        }
+        skeleton.push(c);
+        i += 1;
+    }
+    (bounds, skeleton)
+}
@@ -0,0 +1,201 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{Category, Guideline};
+
+/// Error returned when a string is not the code of a known guideline.
+///
+/// The message includes a "did you mean" hint when the input is close to a known code.
+///
+/// When the token came from a list parsed by [`Guideline::parse_many`], the error also records
+/// the 0-based [`index`](ParseGuidelineError::index) of the token in the list. Its message then
+/// names the position, counting from 1, and [`source`](Error::source) returns an
+/// [`UnknownGuidelineError`] describing the token.
+///
+/// ```rust
+/// use std::error::Error;
+///
+/// use api_guidelines::Guideline;
+///
+/// let err = "C-QWERTY".parse::<Guideline>().unwrap_err();
+/// assert_eq!(err.token(), "C-QWERTY");
+/// assert_eq!(err.index(), None);
+/// assert_eq!(err.to_string(), "unknown guideline 'C-QWERTY'");
+/// assert!(err.source().is_none());
+///
+/// let err = "C-NEWTYP".parse::<Guideline>().unwrap_err();
+/// assert_eq!(err.to_string(), "unknown guideline 'C-NEWTYP'; did you mean 'C-NEWTYPE'?");
+///
+/// let err = Guideline::parse_many("C-CASE, C-CONV, C-XYZ").unwrap_err();
+/// assert_eq!((err.token(), err.index()), ("C-XYZ", Some(2)));
+/// assert_eq!(err.to_string(), "error parsing guideline list at item 3");
+/// assert_eq!(err.source().unwrap().to_string(), "unknown guideline 'C-XYZ'");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGuidelineError {
+    unknown: UnknownGuidelineError,
+    index: Option<usize>,
+}
+
+impl ParseGuidelineError {
+    pub(crate) fn new(token: &str) -> ParseGuidelineError {
+        ParseGuidelineError {
+            unknown: UnknownGuidelineError {
+                token: token.to_owned(),
+            },
+            index: None,
+        }
+    }
+
+    pub(crate) fn at_index(self, index: usize) -> ParseGuidelineError {
+        ParseGuidelineError {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// The input that could not be resolved to a guideline.
+    pub fn token(&self) -> &str {
+        self.unknown.token()
+    }
+
+    /// 0-based position of the token in the list, when parsing a list.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// The closest known guideline, as computed by [`Guideline::suggest`].
+    pub fn suggestion(&self) -> Option<Guideline> {
+        self.unknown.suggestion()
+    }
+}
+
+impl fmt::Display for ParseGuidelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "error parsing guideline list at item {}", index + 1),
+            None => self.unknown.fmt(f),
+        }
+    }
+}
+
+impl Error for ParseGuidelineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.index.map(|_| &self.unknown as _)
+    }
+}
+
+/// A token that is not the code of a known guideline, the [`source`](Error::source) of a
+/// [`ParseGuidelineError`] for a list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGuidelineError {
+    token: String,
+}
+
+impl UnknownGuidelineError {
+    /// The input that could not be resolved to a guideline.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// The closest known guideline, as computed by [`Guideline::suggest`].
+    pub fn suggestion(&self) -> Option<Guideline> {
+        Guideline::suggest(&self.token)
+    }
+}
+
+impl fmt::Display for UnknownGuidelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown guideline '{}'", self.token)?;
+        if let Some(suggestion) = self.suggestion() {
+            write!(f, "; did you mean '{}'?", suggestion.id())?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for UnknownGuidelineError {}
+
+/// Error returned when converting a [`Guideline`] into a category enum it does not belong to.
+///
+/// ```rust
+/// use api_guidelines::{Category, Documentation, Guideline, Naming};
+///
+/// let guideline = Guideline::from(Documentation::C_EXAMPLE);
+/// let err = Naming::try_from(guideline).unwrap_err();
+/// assert_eq!(err.guideline(), guideline);
+/// assert_eq!(err.expected(), Category::Naming);
+/// assert_eq!(err.to_string(), "guideline 'C-EXAMPLE' is not in category Naming");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryMismatchError {
+    guideline: Guideline,
+    expected: Category,
+}
+
+impl CategoryMismatchError {
+    pub(crate) fn new(guideline: Guideline, expected: Category) -> CategoryMismatchError {
+        CategoryMismatchError {
+            guideline,
+            expected,
+        }
+    }
+
+    /// The guideline that failed to convert.
+    pub fn guideline(&self) -> Guideline {
+        self.guideline
+    }
+
+    /// The category that was asked for.
+    pub fn expected(&self) -> Category {
+        self.expected
+    }
+}
+
+impl fmt::Display for CategoryMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "guideline '{}' is not in category {}",
+            self.guideline.id(),
+            self.expected.name()
+        )
+    }
+}
+
+impl Error for CategoryMismatchError {}
+
+/// Error returned when a string is not the name of a [`Category`].
+///
+/// ```rust
+/// use api_guidelines::Category;
+///
+/// let err = "Naminng".parse::<Category>().unwrap_err();
+/// assert_eq!(err.token(), "Naminng");
+/// assert_eq!(err.to_string(), "unknown category 'Naminng'");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCategoryError {
+    token: String,
+}
+
+impl ParseCategoryError {
+    pub(crate) fn new(token: &str) -> ParseCategoryError {
+        ParseCategoryError {
+            token: token.to_owned(),
+        }
+    }
+
+    /// The input that is not a category name.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl fmt::Display for ParseCategoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown category '{}'", self.token)
+    }
+}
+
+impl Error for ParseCategoryError {}
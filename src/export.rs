@@ -0,0 +1,355 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::{Guideline, GuidelineSet, ParseGuidelineError};
+
+/// Streams the whole catalog to `w` as a Markdown checklist: a `##` heading per category followed
+/// by one unchecked `- [ ] C-XXX: Title` item per guideline in official order.
+///
+/// Takes the writer by value per [C-RW-VALUE](crate::Interoperability::C_RW_VALUE); pass
+/// `&mut w` to keep using it afterwards.
+///
+/// # Errors
+///
+/// Returns any error from writing to `w`.
+///
+/// ```rust
+/// use api_guidelines::{markdown_checklist, write_checklist};
+///
+/// let mut buf = Vec::new();
+/// write_checklist(&mut buf)?;
+/// assert_eq!(String::from_utf8(buf).unwrap(), markdown_checklist());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write_checklist<W: Write>(mut w: W) -> io::Result<()> {
+    write!(w, "{}", markdown_checklist_display())
+}
+
+/// Renders the whole catalog as a Markdown checklist, see [`write_checklist`].
+///
+/// ```rust
+/// use api_guidelines::{markdown_checklist, Guideline};
+///
+/// let checklist = markdown_checklist();
+/// assert!(checklist.starts_with("# Rust API Guidelines Checklist\n\n## Naming\n\n"));
+/// assert!(checklist.contains("\n- [ ] C-CASE: Casing conforms to RFC 430\n"));
+/// assert_eq!(checklist.matches("- [ ] ").count(), Guideline::COUNT);
+/// ```
+pub fn markdown_checklist() -> String {
+    markdown_checklist_display().to_string()
+}
+
+/// Like [`markdown_checklist`], but returns a value that writes the checklist when formatted, so
+/// it can be streamed into a formatter without building a `String` first.
+///
+/// ```rust
+/// use api_guidelines::{markdown_checklist, markdown_checklist_display};
+///
+/// assert_eq!(format!("{}", markdown_checklist_display()), markdown_checklist());
+/// ```
+pub fn markdown_checklist_display() -> impl fmt::Display {
+    MarkdownChecklist {
+        addressed: GuidelineSet::new(),
+    }
+}
+
+/// Renders the whole catalog as a Markdown checklist like [`markdown_checklist`], with the
+/// guidelines in `addressed` checked.
+///
+/// [`parse_checklist`] reads the set back, which makes this a persistence format for review
+/// state.
+///
+/// ```rust
+/// use api_guidelines::{
+///     parse_checklist, render_checklist, Debuggability, Documentation, Guideline, GuidelineSet,
+///     Naming,
+/// };
+///
+/// let addressed: GuidelineSet = [
+///     Guideline::from(Naming::C_CASE),
+///     Debuggability::C_DEBUG.into(),
+///     Documentation::C_EXAMPLE.into(),
+/// ]
+/// .into_iter()
+/// .collect();
+/// let checklist = render_checklist(&addressed);
+/// assert!(checklist.contains("\n- [x] C-CASE: Casing conforms to RFC 430\n"));
+/// assert!(checklist.contains("\n- [ ] C-CONV: "));
+/// assert_eq!(parse_checklist(&checklist)?, addressed);
+/// # Ok::<(), api_guidelines::ParseGuidelineError>(())
+/// ```
+pub fn render_checklist(addressed: &GuidelineSet) -> String {
+    MarkdownChecklist {
+        addressed: *addressed,
+    }
+    .to_string()
+}
+
+struct MarkdownChecklist {
+    addressed: GuidelineSet,
+}
+
+impl fmt::Display for MarkdownChecklist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Rust API Guidelines Checklist")?;
+        let mut current = None;
+        for guideline in Guideline::all() {
+            let category = guideline.category();
+            if current != Some(category) {
+                writeln!(f, "\n## {}\n", category.name())?;
+                current = Some(category);
+            }
+            write_checklist_line(f, *guideline, self.addressed.contains(*guideline))?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_checklist_line(
+    f: &mut impl fmt::Write,
+    guideline: Guideline,
+    checked: bool,
+) -> fmt::Result {
+    let mark = if checked { 'x' } else { ' ' };
+    write!(f, "- [{mark}] {}: {}", guideline.id(), guideline.title())
+}
+
+impl Guideline {
+    /// The guideline as a Markdown checklist item, `- [x] C-XXX: Title` when `checked` and
+    /// `- [ ] C-XXX: Title` otherwise, without a trailing newline.
+    ///
+    /// [`parse_checklist_items`] reads it back.
+    ///
+    /// ```rust
+    /// use api_guidelines::{parse_checklist_items, Guideline, Naming};
+    ///
+    /// let guideline = Guideline::from(Naming::C_CASE);
+    /// assert_eq!(guideline.checklist_line(true), "- [x] C-CASE: Casing conforms to RFC 430");
+    /// for checked in [true, false] {
+    ///     let line = guideline.checklist_line(checked);
+    ///     assert_eq!(parse_checklist_items(&line)?, [(guideline, checked)]);
+    /// }
+    /// # Ok::<(), api_guidelines::ParseGuidelineError>(())
+    /// ```
+    pub fn checklist_line(self, checked: bool) -> String {
+        let mut line = String::new();
+        write_checklist_line(&mut line, self, checked).expect("writing to a String never fails");
+        line
+    }
+}
+
+/// Reads the items of a Markdown checklist such as the one from [`markdown_checklist`], returning
+/// each guideline with whether it is checked, in input order.
+///
+/// Items are lines like `- [x] C-XXX: Title`, with `-` or `*` bullets, `x` or `X` checks and an
+/// optional title. Other lines, such as headings, are skipped.
+///
+/// # Errors
+///
+/// Returns an error for the first item whose code is not a known guideline, with its
+/// [`index`](ParseGuidelineError::index) among the items.
+///
+/// ```rust
+/// use api_guidelines::{parse_checklist_items, Guideline, Naming};
+///
+/// let items = parse_checklist_items("## Naming\n\n* [X] C-CASE\n- [ ] C-CONV: Conversions\n")?;
+/// assert_eq!(items, [(Naming::C_CASE.into(), true), (Naming::C_CONV.into(), false)]);
+///
+/// let err = parse_checklist_items("- [x] C-CASE\n- [x] C-BOGUS: Bogus").unwrap_err();
+/// assert_eq!((err.token(), err.index()), ("C-BOGUS", Some(1)));
+/// # Ok::<(), api_guidelines::ParseGuidelineError>(())
+/// ```
+pub fn parse_checklist_items(input: &str) -> Result<Vec<(Guideline, bool)>, ParseGuidelineError> {
+    input
+        .lines()
+        .filter_map(parse_item)
+        .enumerate()
+        .map(|(index, (checked, code))| {
+            code.parse()
+                .map(|guideline| (guideline, checked))
+                .map_err(|err: ParseGuidelineError| err.at_index(index))
+        })
+        .collect()
+}
+
+/// Reads the checked guidelines of a Markdown checklist, the inverse of [`render_checklist`]. See
+/// [`parse_checklist_items`] for the accepted syntax.
+///
+/// # Errors
+///
+/// Returns an error for the first item whose code is not a known guideline.
+///
+/// ```rust
+/// use api_guidelines::{parse_checklist, Naming};
+///
+/// let addressed = parse_checklist("- [x] C-CASE: Casing conforms to RFC 430\n- [ ] C-CONV\n")?;
+/// assert_eq!(addressed.iter().collect::<Vec<_>>(), [Naming::C_CASE.into()]);
+/// # Ok::<(), api_guidelines::ParseGuidelineError>(())
+/// ```
+pub fn parse_checklist(input: &str) -> Result<GuidelineSet, ParseGuidelineError> {
+    Ok(parse_checklist_items(input)?
+        .into_iter()
+        .filter(|&(_, checked)| checked)
+        .map(|(guideline, _)| guideline)
+        .collect())
+}
+
+/// Splits a checklist item line into its checked state and code.
+fn parse_item(line: &str) -> Option<(bool, &str)> {
+    let rest = line.trim().strip_prefix(['-', '*'])?.trim_start();
+    let (mark, rest) = rest.strip_prefix('[')?.split_once(']')?;
+    let checked = match mark {
+        " " => false,
+        "x" | "X" => true,
+        _ => return None,
+    };
+    let code = rest.split(':').next().unwrap_or(rest).trim();
+    Some((checked, code))
+}
+
+/// Renders the whole catalog as CSV with the header `id,category,title,url`, one row per
+/// guideline in official order.
+///
+/// Fields containing commas, quotes or line breaks are quoted as described in RFC 4180.
+///
+/// ```rust
+/// use api_guidelines::{to_csv, Guideline};
+///
+/// let csv = to_csv();
+/// let mut lines = csv.lines();
+/// assert_eq!(lines.next(), Some("id,category,title,url"));
+/// assert_eq!(lines.count(), Guideline::COUNT);
+/// assert!(csv.contains(",\"Ad-hoc conversions follow as_, to_, into_ conventions\","));
+/// ```
+pub fn to_csv() -> String {
+    csv_display().to_string()
+}
+
+/// Like [`to_csv`], but returns a value that writes the CSV when formatted, without building a
+/// `String` first.
+///
+/// ```rust
+/// use api_guidelines::{csv_display, to_csv};
+///
+/// assert_eq!(csv_display().to_string(), to_csv());
+/// ```
+pub fn csv_display() -> impl fmt::Display {
+    Csv
+}
+
+struct Csv;
+
+impl fmt::Display for Csv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("id,category,title,url\n")?;
+        for guideline in Guideline::all() {
+            let fields = [
+                guideline.id(),
+                guideline.category().name(),
+                guideline.title(),
+                guideline.url(),
+            ];
+            for (i, field) in fields.into_iter().enumerate() {
+                if i > 0 {
+                    f.write_str(",")?;
+                }
+                write_csv_field(f, field)?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+fn write_csv_field(f: &mut fmt::Formatter<'_>, field: &str) -> fmt::Result {
+    if field.contains([',', '"', '\n', '\r']) {
+        f.write_str("\"")?;
+        for (i, part) in field.split('"').enumerate() {
+            if i > 0 {
+                f.write_str("\"\"")?;
+            }
+            f.write_str(part)?;
+        }
+        f.write_str("\"")
+    } else {
+        f.write_str(field)
+    }
+}
+
+/// Renders the whole catalog as an HTML fragment: one `<section>` per category, each with a
+/// heading and a list of links to the guidelines in official order.
+///
+/// All text is HTML-escaped with [`escape_html`], so the fragment can be embedded in a page as-is.
+///
+/// ```rust
+/// use api_guidelines::to_html;
+///
+/// let html = to_html();
+/// assert_eq!(html.matches("<section>").count(), 11);
+/// assert!(html.contains(
+///     "<li><a href=\"https://rust-lang.github.io/api-guidelines/naming.html#casing-conforms-to-rfc-430-c-case\">C-CASE — Casing conforms to RFC 430</a></li>"
+/// ));
+///
+/// // Special characters in titles are escaped, so every `&` starts an entity.
+/// assert!(html.contains("Serde&#39;s Serialize"));
+/// assert!(!html.contains("Serde's"));
+/// assert!(html.match_indices('&').all(|(i, _)| {
+///     ["&amp;", "&lt;", "&gt;", "&quot;", "&#39;"].iter().any(|e| html[i..].starts_with(e))
+/// }));
+/// ```
+pub fn to_html() -> String {
+    let mut html = String::new();
+    let mut current = None;
+    for guideline in Guideline::all() {
+        let category = guideline.category();
+        if current != Some(category) {
+            if current.is_some() {
+                html.push_str("  </ul>\n</section>\n");
+            }
+            html.push_str("<section>\n  <h2>");
+            push_html_escaped(&mut html, category.name());
+            html.push_str("</h2>\n  <ul>\n");
+            current = Some(category);
+        }
+        html.push_str("    <li><a href=\"");
+        push_html_escaped(&mut html, guideline.url());
+        html.push_str("\">");
+        push_html_escaped(&mut html, guideline.id());
+        html.push_str(" — ");
+        push_html_escaped(&mut html, guideline.title());
+        html.push_str("</a></li>\n");
+    }
+    if current.is_some() {
+        html.push_str("  </ul>\n</section>\n");
+    }
+    html
+}
+
+/// Escapes `&`, `<`, `>`, `"` and `'` the way [`to_html`] does, for text a page embeds next to
+/// the fragment.
+///
+/// ```rust
+/// use api_guidelines::escape_html;
+///
+/// assert_eq!(escape_html("Fish & <Chips>"), "Fish &amp; &lt;Chips&gt;");
+/// assert_eq!(escape_html(r#"say "hi" & 'bye'"#), "say &quot;hi&quot; &amp; &#39;bye&#39;");
+/// ```
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    push_html_escaped(&mut escaped, text);
+    escaped
+}
+
+fn push_html_escaped(html: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            _ => html.push(c),
+        }
+    }
+}
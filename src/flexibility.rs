@@ -0,0 +1,186 @@
+//! Helpers for applying the [`Flexibility`] guidelines.
+
+pub use crate::Flexibility;
+
+/// Suggests a richer return type for a function that computes useful intermediate data but
+/// throws it away, per [C-INTERMEDIATE](crate::Flexibility::C_INTERMEDIATE).
+///
+/// `computes_extra` tells whether the function has such data at hand. The hint follows the
+/// standard library examples from the guideline: `binary_search`, `String::from_utf8` and
+/// `HashMap::insert`.
+///
+/// ```rust
+/// use api_guidelines::flexibility::intermediate_return_hint;
+///
+/// let hint = intermediate_return_hint("bool", true).unwrap();
+/// assert!(hint.contains("index"));
+///
+/// assert!(intermediate_return_hint("()", true).unwrap().contains("HashMap::insert"));
+/// assert_eq!(intermediate_return_hint("bool", false), None);
+/// ```
+pub fn intermediate_return_hint(return_type: &str, computes_extra: bool) -> Option<String> {
+    if !computes_extra {
+        return None;
+    }
+    let return_type = return_type.trim();
+    let hint = match return_type {
+        "bool" => "return the index that was found instead of `bool`, and where the value would be inserted otherwise, e.g. `Result<usize, usize>` like `slice::binary_search`".to_owned(),
+        "Option<usize>" => "return `Result<usize, usize>` so that a miss also reports where the value would be inserted, like `slice::binary_search`".to_owned(),
+        "" | "()" => "return the value that was replaced, like `HashMap::insert` returning `Option<V>`".to_owned(),
+        _ if return_type.starts_with("Result<") => "let the error carry the intermediate result and hand back ownership of the input, like the error of `String::from_utf8`".to_owned(),
+        _ => format!("consider exposing the intermediate data alongside `{return_type}` so callers don't have to recompute it"),
+    };
+    Some(hint)
+}
+
+/// What a function body does with a parameter, see [`suggest_generic_param`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamUsage {
+    /// The parameter is only iterated over.
+    Iterates,
+    /// The parameter is only read through a shared reference.
+    Reads,
+    /// The parameter is used as a path to open a file.
+    OpensPath,
+    /// Anything else; no suggestion is made.
+    Other,
+}
+
+/// Suggests a more general parameter type, per [C-GENERIC](crate::Flexibility::C_GENERIC).
+///
+/// | Usage       | Concrete type                                | Suggestion                     |
+/// |-------------|----------------------------------------------|--------------------------------|
+/// | `Iterates`  | `Vec<T>`, `[T]` or a reference to one        | `impl IntoIterator<Item = T>`  |
+/// | `OpensPath` | `Path`, `PathBuf`, `str`, `String` or a reference to one | `impl AsRef<Path>` |
+/// | `Reads`     | `&Vec<T>`, `&String`, `&PathBuf`, `&OsString` | `&[T]`, `&str`, `&Path`, `&OsStr` |
+///
+/// ```rust
+/// use api_guidelines::flexibility::{suggest_generic_param, ParamUsage};
+///
+/// assert_eq!(
+///     suggest_generic_param("&Vec<i64>", ParamUsage::Iterates).as_deref(),
+///     Some("impl IntoIterator<Item = i64>"),
+/// );
+/// assert_eq!(
+///     suggest_generic_param("&Path", ParamUsage::OpensPath).as_deref(),
+///     Some("impl AsRef<Path>"),
+/// );
+/// assert_eq!(suggest_generic_param("&String", ParamUsage::Reads).as_deref(), Some("&str"));
+/// assert_eq!(suggest_generic_param("u32", ParamUsage::Other), None);
+/// ```
+pub fn suggest_generic_param(concrete_type: &str, usage: ParamUsage) -> Option<String> {
+    let ty = concrete_type.trim();
+    let owned = ty
+        .strip_prefix('&')
+        .map_or(ty, |rest| rest.strip_prefix("mut ").unwrap_or(rest).trim());
+    match usage {
+        ParamUsage::Iterates => {
+            let item = owned
+                .strip_prefix("Vec<")
+                .and_then(|rest| rest.strip_suffix('>'))
+                .or_else(|| {
+                    owned
+                        .strip_prefix('[')
+                        .and_then(|rest| rest.strip_suffix(']'))
+                })?;
+            Some(format!("impl IntoIterator<Item = {}>", item.trim()))
+        }
+        ParamUsage::OpensPath => matches!(owned, "Path" | "PathBuf" | "str" | "String")
+            .then(|| "impl AsRef<Path>".to_owned()),
+        ParamUsage::Reads => {
+            if !ty.starts_with('&') || ty.starts_with("&mut") {
+                return None;
+            }
+            match owned {
+                "String" => Some("&str".to_owned()),
+                "PathBuf" => Some("&Path".to_owned()),
+                "OsString" => Some("&OsStr".to_owned()),
+                _ => owned
+                    .strip_prefix("Vec<")
+                    .and_then(|rest| rest.strip_suffix('>'))
+                    .map(|item| format!("&[{}]", item.trim())),
+            }
+        }
+        ParamUsage::Other => None,
+    }
+}
+
+/// The parts of a trait method signature relevant to object safety, see [`object_safety_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MethodSig<'a> {
+    pub name: &'a str,
+    /// The method has type parameters, e.g. `fn f<T>(&self, t: T)`.
+    pub is_generic: bool,
+    /// `Self` appears outside the receiver, e.g. `fn eq(&self, other: &Self)` or `-> Self`.
+    pub uses_self_outside_receiver: bool,
+    /// The method already has a `where Self: Sized` clause.
+    pub where_self_sized: bool,
+}
+
+/// A method that prevents its trait from being used as a trait object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OffendingMethod {
+    pub name: String,
+    /// Why the method is not object-safe.
+    pub reason: &'static str,
+    /// How to exclude the method from the trait object.
+    pub suggestion: String,
+}
+
+/// Result of [`object_safety_report`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ObjectSafetyReport {
+    /// Methods that make the trait not object-safe, in declaration order.
+    pub offending: Vec<OffendingMethod>,
+}
+
+impl ObjectSafetyReport {
+    /// Returns whether the trait can be used as `dyn Trait`.
+    pub fn is_object_safe(&self) -> bool {
+        self.offending.is_empty()
+    }
+}
+
+/// Reports whether a trait with the given methods is object-safe, per
+/// [C-OBJECT](crate::Flexibility::C_OBJECT).
+///
+/// Generic methods and methods using `Self` outside the receiver position are flagged, unless they
+/// are already excluded from the trait object with `where Self: Sized`, which is also the suggested
+/// fix.
+///
+/// ```rust
+/// use api_guidelines::flexibility::{object_safety_report, MethodSig};
+///
+/// let report = object_safety_report(&[
+///     MethodSig { name: "object_safe", is_generic: false, uses_self_outside_receiver: false, where_self_sized: false },
+///     MethodSig { name: "not_object_safe", is_generic: true, uses_self_outside_receiver: false, where_self_sized: false },
+/// ]);
+/// assert!(!report.is_object_safe());
+/// assert_eq!(report.offending.len(), 1);
+/// assert_eq!(report.offending[0].name, "not_object_safe");
+/// assert!(report.offending[0].suggestion.contains("where Self: Sized"));
+/// ```
+pub fn object_safety_report(methods: &[MethodSig<'_>]) -> ObjectSafetyReport {
+    let offending = methods
+        .iter()
+        .filter(|method| !method.where_self_sized)
+        .filter_map(|method| {
+            let reason = if method.is_generic {
+                "generic methods cannot be represented as a single vtable entry"
+            } else if method.uses_self_outside_receiver {
+                "`Self` is only usable in receiver position on trait objects"
+            } else {
+                return None;
+            };
+            Some(OffendingMethod {
+                name: method.name.to_owned(),
+                reason,
+                suggestion: format!(
+                    "add `where Self: Sized` to `{}` to exclude it from the trait object",
+                    method.name
+                ),
+            })
+        })
+        .collect();
+    ObjectSafetyReport { offending }
+}
@@ -0,0 +1,287 @@
+//! Helpers for applying the [`FutureProofing`] guidelines.
+
+pub use crate::FutureProofing;
+
+/// Generates the [C-SEALED](crate::FutureProofing::C_SEALED) boilerplate for a trait implemented by
+/// `impl_types`.
+///
+/// The output contains the documented public trait with a `private::Sealed` supertrait, an impl of
+/// the trait for each type, and the `private` module implementing `Sealed` for those same types.
+///
+/// ```rust
+/// use api_guidelines::future_proofing::sealed_trait_sketch;
+///
+/// let code = sealed_trait_sketch("TheTrait", &["usize", "Widget"]);
+/// assert!(code.contains("This trait is sealed"));
+/// assert!(code.contains("pub trait TheTrait: private::Sealed"));
+/// assert!(code.contains("mod private"));
+/// assert!(code.contains("impl Sealed for usize {}"));
+/// assert!(code.contains("impl Sealed for Widget {}"));
+///
+/// let file = syn::parse_file(&format!("struct Widget;\n{code}"));
+/// assert!(file.is_ok());
+/// ```
+pub fn sealed_trait_sketch(trait_name: &str, impl_types: &[&str]) -> String {
+    let mut code = format!(
+        "/// This trait is sealed and cannot be implemented for types outside this crate.\n\
+         pub trait {trait_name}: private::Sealed {{\n    \
+             // Methods that downstream crates are allowed to call.\n\
+         }}\n"
+    );
+    for ty in impl_types {
+        code.push_str(&format!("\nimpl {trait_name} for {ty} {{}}\n"));
+    }
+    code.push_str("\nmod private {\n");
+    if !impl_types.is_empty() {
+        code.push_str("    use super::*;\n\n");
+    }
+    code.push_str("    pub trait Sealed {}\n");
+    if !impl_types.is_empty() {
+        code.push_str("\n    // Implement for those same types, but no others.\n");
+    }
+    for ty in impl_types {
+        code.push_str(&format!("    impl Sealed for {ty} {{}}\n"));
+    }
+    code.push_str("}\n");
+    code
+}
+
+/// Traits that [C-STRUCT-BOUNDS](crate::FutureProofing::C_STRUCT_BOUNDS) says should never be used
+/// as bounds on data structures.
+const DERIVABLE_BOUNDS: &[&str] = &[
+    "Clone",
+    "Copy",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "Debug",
+    "Display",
+    "Default",
+    "Error",
+    "Serialize",
+    "Deserialize",
+    "DeserializeOwned",
+];
+
+/// Checks the trait bounds on a data structure's type parameters against
+/// [C-STRUCT-BOUNDS](crate::FutureProofing::C_STRUCT_BOUNDS), returning one message per bound.
+///
+/// Derivable traits such as `Clone` or `Debug` are flagged outright. Other bounds like `Read` fall
+/// in the guideline's gray area and only produce a warning mentioning it. `?Sized` is always
+/// accepted. Bounds the structure needs for an associated type used in a field or for its `Drop`
+/// impl are documented exceptions; pass them in `keep` and they are accepted too.
+///
+/// Paths and generic arguments are ignored, both in `generic_bounds` and in `keep`, so
+/// `std::fmt::Debug` and `Deserialize<'de>` are recognized too.
+///
+/// ```rust
+/// use api_guidelines::future_proofing::check_struct_bounds;
+///
+/// let messages = check_struct_bounds(&["Clone", "Debug", "Read"], &[]);
+/// assert_eq!(messages.len(), 3);
+/// assert!(messages[0].starts_with("`Clone`") && !messages[0].contains("gray area"));
+/// assert!(messages[1].starts_with("`Debug`") && !messages[1].contains("gray area"));
+/// assert!(messages[2].starts_with("`Read`") && messages[2].contains("gray area"));
+///
+/// assert!(check_struct_bounds(&["?Sized"], &[]).is_empty());
+///
+/// // `Debug` is needed by the struct's `Drop` impl.
+/// let messages = check_struct_bounds(&["Clone", "std::fmt::Debug"], &["Debug"]);
+/// assert_eq!(messages.len(), 1);
+/// assert!(messages[0].starts_with("`Clone`"));
+/// ```
+pub fn check_struct_bounds(generic_bounds: &[&str], keep: &[&str]) -> Vec<String> {
+    generic_bounds
+        .iter()
+        .map(|bound| bound.trim())
+        .filter(|bound| *bound != "?Sized" && !is_kept(bound, keep))
+        .map(|bound| {
+            if is_derivable_bound(bound) {
+                format!(
+                    "`{bound}` should not be a bound on a data structure; derive it and bound the impls that need it instead"
+                )
+            } else {
+                format!(
+                    "`{bound}` is in the gray area; keep it only if it communicates behavior the data structure requires"
+                )
+            }
+        })
+        .collect()
+}
+
+/// Removes the derivable bounds that [C-STRUCT-BOUNDS](crate::FutureProofing::C_STRUCT_BOUNDS)
+/// forbids from a generic parameter list, for auto-fixing what [`check_struct_bounds`] reports.
+///
+/// Every other bound is kept, including gray-area bounds like `Read` and `?Sized`. Derivable
+/// bounds that are documented exceptions, i.e. needed for an associated type used in a field or by
+/// the struct's `Drop` impl, must be listed in `keep`, or the fix may not compile. Lifetimes, const
+/// parameters and defaults are preserved, and a parameter left without bounds loses its `:`. The
+/// output is normalized to `, ` and ` + ` separators.
+///
+/// ```rust
+/// use api_guidelines::future_proofing::strip_struct_bounds;
+///
+/// assert_eq!(strip_struct_bounds("<T: Clone + Read>", &[]), "<T: Read>");
+/// assert_eq!(strip_struct_bounds("<T: Clone + Debug + PartialOrd>", &[]), "<T>");
+/// assert_eq!(strip_struct_bounds("<T: Clone + Debug>", &["Debug"]), "<T: Debug>");
+/// assert_eq!(
+///     strip_struct_bounds(
+///         "<'a, T: std::fmt::Debug + ?Sized, U: Hash + Fn() -> T = fn() -> T, const N: usize>",
+///         &[],
+///     ),
+///     "<'a, T: ?Sized, U: Fn() -> T = fn() -> T, const N: usize>",
+/// );
+/// ```
+pub fn strip_struct_bounds(generics_src: &str, keep: &[&str]) -> String {
+    let src = generics_src.trim();
+    let inner = src
+        .strip_prefix('<')
+        .and_then(|rest| rest.strip_suffix('>'))
+        .unwrap_or(src);
+    let params: Vec<String> = split_top_level(inner, ',')
+        .into_iter()
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            if param.starts_with('\'') || param.starts_with("const ") {
+                return param.to_owned();
+            }
+            let (param, default) = match split_top_level(param, '=').as_slice() {
+                [param, default, ..] => (*param, Some(default.trim())),
+                _ => (param, None),
+            };
+            let (name, bounds) = match find_bound_colon(param) {
+                Some(colon) => (param[..colon].trim(), &param[colon + 1..]),
+                None => (param.trim(), ""),
+            };
+            let kept: Vec<&str> = split_top_level(bounds, '+')
+                .into_iter()
+                .map(str::trim)
+                .filter(|bound| {
+                    !bound.is_empty() && (!is_derivable_bound(bound) || is_kept(bound, keep))
+                })
+                .collect();
+            let mut param = name.to_owned();
+            if !kept.is_empty() {
+                param.push_str(": ");
+                param.push_str(&kept.join(" + "));
+            }
+            if let Some(default) = default {
+                param.push_str(" = ");
+                param.push_str(default);
+            }
+            param
+        })
+        .collect();
+    format!("<{}>", params.join(", "))
+}
+
+/// Splits `src` at each `sep` that is not nested inside `<>`, `()` or `[]`. The `>` of `->` does
+/// not close anything.
+fn split_top_level(src: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut prev = None;
+    for (i, c) in src.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' if prev == Some('-') => {}
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            _ if c == sep && depth == 0 => {
+                parts.push(&src[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+        prev = Some(c);
+    }
+    parts.push(&src[start..]);
+    parts
+}
+
+/// Finds the `:` separating a type parameter from its bounds, skipping the `::` of paths.
+fn find_bound_colon(param: &str) -> Option<usize> {
+    let bytes = param.as_bytes();
+    (0..bytes.len()).find(|&i| {
+        bytes[i] == b':' && bytes.get(i + 1) != Some(&b':') && (i == 0 || bytes[i - 1] != b':')
+    })
+}
+
+/// Returns whether a bound names one of the [`DERIVABLE_BOUNDS`], ignoring its path and generic
+/// arguments.
+fn is_derivable_bound(bound: &str) -> bool {
+    DERIVABLE_BOUNDS.contains(&bound_name(bound))
+}
+
+/// Returns whether a bound names one of the exceptions in `keep`, ignoring paths and generic
+/// arguments on both sides.
+fn is_kept(bound: &str, keep: &[&str]) -> bool {
+    keep.iter()
+        .any(|kept| bound_name(kept) == bound_name(bound))
+}
+
+/// Strips the path and generic arguments from a bound, e.g. `serde::Deserialize<'de>` becomes
+/// `Deserialize`.
+fn bound_name(bound: &str) -> &str {
+    let name = bound.split('<').next().unwrap_or(bound);
+    name.rsplit("::").next().unwrap_or(name).trim()
+}
+
+/// A struct field and whether it is public, see [`check_public_fields`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldVis<'a> {
+    pub name: &'a str,
+    /// The field is declared `pub`.
+    pub is_public: bool,
+}
+
+/// Flags the public fields of a struct per
+/// [C-STRUCT-PRIVATE](crate::FutureProofing::C_STRUCT_PRIVATE), returning one note per field.
+///
+/// Public fields are fine on C-spirit structs, i.e. passive data that maintains no invariants, so
+/// nothing is reported when `is_passive_data` is true. Otherwise each public field commits the
+/// struct to its representation and lets callers break its invariants.
+///
+/// ```rust
+/// use api_guidelines::future_proofing::{check_public_fields, FieldVis};
+///
+/// // Reads field visibility the way a linter would, with syn.
+/// let fields = |src: &str| -> Vec<(String, bool)> {
+///     let item: syn::ItemStruct = syn::parse_str(src).unwrap();
+///     item.fields
+///         .iter()
+///         .map(|f| (f.ident.as_ref().unwrap().to_string(), matches!(f.vis, syn::Visibility::Public(_))))
+///         .collect()
+/// };
+/// fn as_vis(fields: &[(String, bool)]) -> Vec<FieldVis<'_>> {
+///     fields.iter().map(|(name, is_public)| FieldVis { name, is_public: *is_public }).collect()
+/// }
+///
+/// // Passive data: public fields are part of the design.
+/// let point = fields("pub struct Point { pub x: i32, pub y: i32 }");
+/// assert!(check_public_fields(true, &as_vis(&point)).is_empty());
+///
+/// // An invariant-bearing struct must not expose its fields.
+/// let buffer = fields("pub struct Buffer { pub len: usize, data: Vec<u8> }");
+/// let notes = check_public_fields(false, &as_vis(&buffer));
+/// assert_eq!(notes.len(), 1);
+/// assert!(notes[0].starts_with("`len`"));
+/// ```
+pub fn check_public_fields(is_passive_data: bool, fields: &[FieldVis]) -> Vec<String> {
+    if is_passive_data {
+        return Vec::new();
+    }
+    fields
+        .iter()
+        .filter(|field| field.is_public)
+        .map(|field| {
+            format!(
+                "`{}` is public; make it private and provide a getter and setter if needed (C-STRUCT-PRIVATE)",
+                field.name
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,1653 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    CategoryMismatchError, Debuggability, Dependability, Documentation, Flexibility,
+    FutureProofing, Interoperability, Macro, Naming, Necessities, ParseCategoryError,
+    ParseGuidelineError, Predictability, Snippet, TypeSafety,
+};
+
+/// A single guideline from any of the categories, ordered as in the official checklist.
+///
+/// Every category enum converts into a `Guideline` through [`From`], so functions taking
+/// `impl Into<Guideline>` accept either form. [`TryFrom`] converts back, failing with a
+/// [`CategoryMismatchError`] for guidelines of another category.
+///
+/// ```rust
+/// use api_guidelines::{Documentation, Guideline, Naming};
+///
+/// let guideline = Guideline::from(Naming::C_CASE);
+/// assert_eq!(guideline, Guideline::Naming(Naming::C_CASE));
+/// assert_eq!(Guideline::all()[0], guideline);
+///
+/// assert_eq!(Naming::try_from(guideline), Ok(Naming::C_CASE));
+/// assert!(Naming::try_from(Guideline::Documentation(Documentation::C_LINK)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Guideline {
+    Naming(Naming),
+    Interoperability(Interoperability),
+    Predictability(Predictability),
+    Flexibility(Flexibility),
+    TypeSafety(TypeSafety),
+    Dependability(Dependability),
+    Debuggability(Debuggability),
+    FutureProofing(FutureProofing),
+    Necessities(Necessities),
+    Documentation(Documentation),
+    Macro(Macro),
+}
+
+impl Guideline {
+    /// The earliest Rust version providing the technique the guideline recommends, or `None` when
+    /// it has always been available.
+    ///
+    /// Useful to warn when a guideline can't be followed under a project's MSRV.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Documentation, FutureProofing, Naming};
+    ///
+    /// // Returning `impl Trait` as an alternative to a newtype.
+    /// assert_eq!(FutureProofing::C_NEWTYPE_HIDE.min_rust_version(), Some("1.26"));
+    /// assert_eq!(Documentation::C_HIDDEN.min_rust_version(), Some("1.18"));
+    /// assert_eq!(Naming::C_CASE.min_rust_version(), None);
+    /// ```
+    pub fn min_rust_version(self) -> Option<&'static str> {
+        match self {
+            // `TryFrom` and `TryInto`.
+            Guideline::Interoperability(Interoperability::C_CONV_TRAITS) => Some("1.34"),
+            // `Error::source`.
+            Guideline::Interoperability(Interoperability::C_GOOD_ERR) => Some("1.30"),
+            // `impl Trait` in return position.
+            Guideline::FutureProofing(FutureProofing::C_NEWTYPE_HIDE) => Some("1.26"),
+            // `main` returning `Result`.
+            Guideline::Documentation(Documentation::C_QUESTION_MARK) => Some("1.26"),
+            // Intra-doc links.
+            Guideline::Documentation(Documentation::C_LINK) => Some("1.48"),
+            // `pub(crate)`.
+            Guideline::Documentation(Documentation::C_HIDDEN) => Some("1.18"),
+            // The `vis` fragment specifier.
+            Guideline::Macro(Macro::C_MACRO_VIS) => Some("1.30"),
+            _ => None,
+        }
+    }
+
+    /// How serious a violation of the guideline is, e.g. to pick a default lint level.
+    ///
+    /// [`Error`](Severity::Error) is for guidelines whose violation causes incorrect behavior or
+    /// breaks downstream crates: thread safety, error types, argument validation, failing
+    /// destructors, and unstable or incompatibly licensed dependencies.
+    /// [`Note`](Severity::Note) is for documentation niceties and conveniences users can live
+    /// without. Everything else affects the shape of the API and is a
+    /// [`Warning`](Severity::Warning).
+    ///
+    /// ```rust
+    /// use api_guidelines::{Documentation, Guideline, Interoperability, Naming, Severity};
+    ///
+    /// assert_eq!(Interoperability::C_SEND_SYNC.severity(), Severity::Error);
+    /// assert_eq!(Naming::C_CASE.severity(), Severity::Warning);
+    /// assert_eq!(Documentation::C_EXAMPLE.severity(), Severity::Note);
+    ///
+    /// for severity in [Severity::Error, Severity::Warning, Severity::Note] {
+    ///     assert!(Guideline::all().iter().any(|g| g.severity() == severity));
+    /// }
+    /// assert!(Severity::Error > Severity::Warning && Severity::Warning > Severity::Note);
+    /// ```
+    pub fn severity(self) -> Severity {
+        match self {
+            Guideline::Interoperability(Interoperability::C_SEND_SYNC)
+            | Guideline::Interoperability(Interoperability::C_GOOD_ERR)
+            | Guideline::Dependability(Dependability::C_VALIDATE)
+            | Guideline::Dependability(Dependability::C_DTOR_FAIL)
+            | Guideline::Necessities(Necessities::C_STABLE)
+            | Guideline::Necessities(Necessities::C_PERMISSIVE) => Severity::Error,
+            Guideline::Naming(Naming::C_ITER_TY)
+            | Guideline::Naming(Naming::C_FEATURE)
+            | Guideline::Naming(Naming::C_WORD_ORDER)
+            | Guideline::Interoperability(Interoperability::C_SERDE)
+            | Guideline::Interoperability(Interoperability::C_NUM_FMT)
+            | Guideline::Flexibility(Flexibility::C_INTERMEDIATE)
+            | Guideline::TypeSafety(TypeSafety::C_BITFLAG)
+            | Guideline::Debuggability(Debuggability::C_DEBUG_NONEMPTY)
+            | Guideline::Documentation(Documentation::C_CRATE_DOC)
+            | Guideline::Documentation(Documentation::C_EXAMPLE)
+            | Guideline::Documentation(Documentation::C_QUESTION_MARK)
+            | Guideline::Documentation(Documentation::C_LINK)
+            | Guideline::Documentation(Documentation::C_METADATA)
+            | Guideline::Documentation(Documentation::C_RELNOTES)
+            | Guideline::Documentation(Documentation::C_HIDDEN)
+            | Guideline::Macro(Macro::C_EVOCATIVE)
+            | Guideline::Macro(Macro::C_MACRO_TY) => Severity::Note,
+            _ => Severity::Warning,
+        }
+    }
+
+    /// Returns whether a tool can check the guideline mechanically.
+    ///
+    /// The criterion is that a violation can be detected from the source code or `Cargo.toml`
+    /// alone, without judging intent or quality: casing, a `get_` prefix, a missing `Debug` impl or
+    /// an `unwrap` in an example are facts, whereas whether the crate docs are thorough enough or
+    /// a type deserves a builder is a call for a reviewer. Linters should check the former and list
+    /// the latter as manual review items.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Documentation, Guideline, Naming};
+    ///
+    /// assert!(Guideline::from(Naming::C_CASE).is_enforceable_by_tooling());
+    /// assert!(!Guideline::from(Documentation::C_CRATE_DOC).is_enforceable_by_tooling());
+    /// ```
+    pub fn is_enforceable_by_tooling(self) -> bool {
+        matches!(
+            self,
+            Guideline::Naming(Naming::C_CASE)
+                | Guideline::Naming(Naming::C_GETTER)
+                | Guideline::Naming(Naming::C_ITER_TY)
+                | Guideline::Naming(Naming::C_FEATURE)
+                | Guideline::Interoperability(Interoperability::C_CONV_TRAITS)
+                | Guideline::Interoperability(Interoperability::C_GOOD_ERR)
+                | Guideline::Interoperability(Interoperability::C_RW_VALUE)
+                | Guideline::Debuggability(Debuggability::C_DEBUG)
+                | Guideline::FutureProofing(FutureProofing::C_STRUCT_BOUNDS)
+                | Guideline::Necessities(Necessities::C_STABLE)
+                | Guideline::Necessities(Necessities::C_PERMISSIVE)
+                | Guideline::Documentation(Documentation::C_QUESTION_MARK)
+                | Guideline::Documentation(Documentation::C_FAILURE)
+                | Guideline::Documentation(Documentation::C_METADATA)
+        )
+    }
+
+    /// Classifies the guideline as correctness, interop, ergonomics, documentation or style, see
+    /// [`GuidelineKind`].
+    ///
+    /// Mostly follows the category, except that C-SEND-SYNC counts as correctness, the
+    /// Debuggability and Necessities guidelines as interop, and C-EVOCATIVE as style.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, GuidelineKind, Interoperability, Naming};
+    ///
+    /// assert_eq!(Guideline::from(Interoperability::C_SEND_SYNC).kind(), GuidelineKind::Correctness);
+    /// assert_eq!(Guideline::from(Naming::C_CASE).kind(), GuidelineKind::Style);
+    /// ```
+    pub fn kind(self) -> GuidelineKind {
+        match self {
+            Guideline::Interoperability(Interoperability::C_SEND_SYNC)
+            | Guideline::Dependability(_) => GuidelineKind::Correctness,
+            Guideline::Interoperability(_)
+            | Guideline::Debuggability(_)
+            | Guideline::Necessities(_) => GuidelineKind::Interop,
+            Guideline::Documentation(_) => GuidelineKind::Documentation,
+            Guideline::Naming(_) | Guideline::Macro(Macro::C_EVOCATIVE) => GuidelineKind::Style,
+            Guideline::Predictability(_)
+            | Guideline::Flexibility(_)
+            | Guideline::TypeSafety(_)
+            | Guideline::FutureProofing(_)
+            | Guideline::Macro(_) => GuidelineKind::Ergonomics,
+        }
+    }
+
+    /// Suggested order for addressing the guidelines in a new crate, from 1 (first) to 6 (last).
+    ///
+    /// The tiers put what is costly to get wrong before what is cheap to fix later:
+    ///
+    /// 1. [`Error`](Severity::Error)-severity guidelines, such as soundness and licensing.
+    /// 2. The remaining correctness and interop guidelines, such as common trait impls.
+    /// 3. Naming and future proofing, which are breaking changes once published.
+    /// 4. The remaining API ergonomics and macro guidelines.
+    /// 5. Essential documentation: crate docs, examples, failure sections and metadata.
+    /// 6. Documentation polish.
+    ///
+    /// Sort by `(priority, as_index)` for a total order within a tier.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Documentation, Guideline, Interoperability};
+    ///
+    /// let send_sync = Guideline::from(Interoperability::C_SEND_SYNC);
+    /// let relnotes = Guideline::from(Documentation::C_RELNOTES);
+    /// assert!(send_sync.priority() < relnotes.priority());
+    /// assert!(Guideline::all().iter().all(|g| (1..=6).contains(&g.priority())));
+    /// ```
+    pub fn priority(self) -> u8 {
+        if self.severity() == Severity::Error {
+            return 1;
+        }
+        match self.kind() {
+            GuidelineKind::Correctness | GuidelineKind::Interop => 2,
+            GuidelineKind::Style | GuidelineKind::Ergonomics => match self {
+                Guideline::Naming(_) | Guideline::FutureProofing(_) => 3,
+                _ => 4,
+            },
+            GuidelineKind::Documentation => match self {
+                Guideline::Documentation(
+                    Documentation::C_CRATE_DOC
+                    | Documentation::C_EXAMPLE
+                    | Documentation::C_FAILURE
+                    | Documentation::C_METADATA,
+                ) => 5,
+                _ => 6,
+            },
+        }
+    }
+
+    /// The category, i.e. chapter of the guidelines book, the guideline belongs to.
+    pub fn category(self) -> Category {
+        match self {
+            Guideline::Naming(_) => Category::Naming,
+            Guideline::Interoperability(_) => Category::Interoperability,
+            Guideline::Predictability(_) => Category::Predictability,
+            Guideline::Flexibility(_) => Category::Flexibility,
+            Guideline::TypeSafety(_) => Category::TypeSafety,
+            Guideline::Dependability(_) => Category::Dependability,
+            Guideline::Debuggability(_) => Category::Debuggability,
+            Guideline::FutureProofing(_) => Category::FutureProofing,
+            Guideline::Necessities(_) => Category::Necessities,
+            Guideline::Documentation(_) => Category::Documentation,
+            Guideline::Macro(_) => Category::Macro,
+        }
+    }
+
+    /// The guideline's code without the leading `C-`, e.g. `"CASE"` or `"SEND-SYNC"`.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Interoperability};
+    ///
+    /// assert_eq!(Guideline::from(Interoperability::C_SEND_SYNC).short_code(), "SEND-SYNC");
+    /// for guideline in Guideline::all() {
+    ///     assert_eq!(format!("C-{}", guideline.short_code()), guideline.id());
+    /// }
+    /// ```
+    pub fn short_code(self) -> &'static str {
+        let id = self.id();
+        id.strip_prefix("C-").unwrap_or(id)
+    }
+
+    /// A citation of the guideline for design documents and PR descriptions.
+    ///
+    /// The format is stable: `Rust API Guidelines: {title} ({id}) <{url}>`.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Naming};
+    ///
+    /// let guideline = Guideline::from(Naming::C_CASE);
+    /// let citation = guideline.cite();
+    /// assert_eq!(
+    ///     citation,
+    ///     "Rust API Guidelines: Casing conforms to RFC 430 (C-CASE) \
+    ///      <https://rust-lang.github.io/api-guidelines/naming.html#casing-conforms-to-rfc-430-c-case>",
+    /// );
+    /// assert!(citation.contains(guideline.id()) && citation.contains(guideline.title()));
+    /// assert!(citation.contains(guideline.url()));
+    /// ```
+    pub fn cite(self) -> String {
+        format!(
+            "Rust API Guidelines: {} ({}) <{}>",
+            self.title(),
+            self.id(),
+            self.url()
+        )
+    }
+
+    /// Wayback Machine timestamp used by [`web_archive_url`](Guideline::web_archive_url).
+    ///
+    /// The archive redirects a partial timestamp to the closest snapshot it has, so this pins links
+    /// to the guidelines book as of the start of 2024 without naming an exact capture.
+    pub const WEB_ARCHIVE_SNAPSHOT: &'static str = "20240101";
+
+    /// Link to an archived copy of [`url`](Guideline::url) on the Wayback Machine, for citations
+    /// that should keep working if the book is reorganized.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Naming};
+    ///
+    /// let guideline = Guideline::from(Naming::C_CASE);
+    /// let archived = guideline.web_archive_url();
+    /// assert!(archived.starts_with("https://web.archive.org/web/20240101/"));
+    /// assert!(archived.ends_with(guideline.url()));
+    /// ```
+    pub fn web_archive_url(self) -> String {
+        format!(
+            "https://web.archive.org/web/{}/{}",
+            Self::WEB_ARCHIVE_SNAPSHOT,
+            self.url()
+        )
+    }
+
+    /// Returns whether `code` is the guideline's code, ignoring ASCII case and surrounding
+    /// whitespace.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Category, Guideline, Naming};
+    ///
+    /// let guideline = Guideline::from(Naming::C_CASE);
+    /// assert!(guideline.matches_code("C-CASE"));
+    /// assert!(guideline.matches_code("c-Case"));
+    /// assert!(!guideline.matches_code("C-CONV"));
+    ///
+    /// assert!(guideline.is_in(Category::Naming));
+    /// assert!(!guideline.is_in(Category::Documentation));
+    /// ```
+    pub fn matches_code(self, code: &str) -> bool {
+        self.id().eq_ignore_ascii_case(code.trim())
+    }
+
+    /// Returns whether the guideline belongs to `category`.
+    pub fn is_in(self, category: Category) -> bool {
+        self.category() == category
+    }
+
+    /// 0-based position of the guideline's category in the official chapter order.
+    pub fn chapter_index(self) -> usize {
+        self.category() as usize
+    }
+
+    /// 0-based position of the guideline within its chapter.
+    ///
+    /// Together with [`chapter_index`](Guideline::chapter_index) this gives "2.3"-style numbering
+    /// that follows the order of [`Guideline::all`] and `Ord`.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Interoperability};
+    ///
+    /// let guideline = Guideline::from(Interoperability::C_COLLECT);
+    /// assert_eq!((guideline.chapter_index(), guideline.position_in_chapter()), (1, 2));
+    ///
+    /// let numbers: Vec<_> = Guideline::all()
+    ///     .iter()
+    ///     .map(|g| (g.chapter_index(), g.position_in_chapter()))
+    ///     .collect();
+    /// assert!(numbers.windows(2).all(|pair| pair[0] < pair[1]));
+    /// assert!(Guideline::all().windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    pub fn position_in_chapter(self) -> usize {
+        match self {
+            Guideline::Naming(g) => g as usize,
+            Guideline::Interoperability(g) => g as usize,
+            Guideline::Predictability(g) => g as usize,
+            Guideline::Flexibility(g) => g as usize,
+            Guideline::TypeSafety(g) => g as usize,
+            Guideline::Dependability(g) => g as usize,
+            Guideline::Debuggability(g) => g as usize,
+            Guideline::FutureProofing(g) => g as usize,
+            Guideline::Necessities(g) => g as usize,
+            Guideline::Documentation(g) => g as usize,
+            Guideline::Macro(g) => g as usize,
+        }
+    }
+
+    /// Looks up a guideline by its code or one of its [`aliases`](Guideline::aliases), ignoring
+    /// ASCII case.
+    ///
+    /// To warn about outdated spellings, compare the input with the [`id`](Guideline::id) of the
+    /// result.
+    ///
+    /// ```rust
+    /// use api_guidelines::{FutureProofing, Guideline, Naming};
+    ///
+    /// assert_eq!(Guideline::from_id("C-CASE"), Some(Guideline::from(Naming::C_CASE)));
+    /// assert_eq!(Guideline::from_id("c-case"), Some(Guideline::from(Naming::C_CASE)));
+    /// assert_eq!(Guideline::from_id("C-NOPE"), None);
+    ///
+    /// let bounds = Guideline::from_id("C-STRUCT-BOUND").unwrap();
+    /// assert_eq!(bounds, FutureProofing::C_STRUCT_BOUNDS.into());
+    /// assert_eq!(bounds.id(), "C-STRUCT-BOUNDS");
+    /// ```
+    pub fn from_id(id: &str) -> Option<Guideline> {
+        // Codes are uppercase, so comparing with the uppercased input keeps the table's order.
+        BY_ID
+            .binary_search_by(|(code, _)| {
+                code.bytes().cmp(id.bytes().map(|b| b.to_ascii_uppercase()))
+            })
+            .ok()
+            .map(|index| BY_ID[index].1)
+            .or_else(|| {
+                ALL.iter().copied().find(|g| {
+                    g.aliases()
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(id))
+                })
+            })
+    }
+
+    /// Looks up a guideline by its [`url`](Guideline::url), accepting `http` as well as `https`
+    /// and a trailing slash.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Naming};
+    ///
+    /// let case = Guideline::from(Naming::C_CASE);
+    /// assert_eq!(Guideline::from_url(case.url()), Some(case));
+    /// assert_eq!(
+    ///     Guideline::from_url("http://rust-lang.github.io/api-guidelines/naming.html#casing-conforms-to-rfc-430-c-case/"),
+    ///     Some(case),
+    /// );
+    /// assert_eq!(Guideline::from_url("https://example.com/naming.html#c-case"), None);
+    /// ```
+    pub fn from_url(url: &str) -> Option<Guideline> {
+        fn strip_scheme(url: &str) -> Option<&str> {
+            let url = url.trim();
+            let url = url.strip_suffix('/').unwrap_or(url);
+            url.strip_prefix("https://")
+                .or_else(|| url.strip_prefix("http://"))
+        }
+
+        let url = strip_scheme(url)?;
+        ALL.iter()
+            .copied()
+            .find(|g| strip_scheme(g.url()) == Some(url))
+    }
+
+    /// Alternate spellings of the guideline's code that [`from_id`](Guideline::from_id) also
+    /// accepts, so configuration files written against older or misremembered codes keep working.
+    ///
+    /// ```rust
+    /// use api_guidelines::{FutureProofing, Guideline, Naming};
+    ///
+    /// assert_eq!(Guideline::from(FutureProofing::C_STRUCT_BOUNDS).aliases(), ["C-STRUCT-BOUND"]);
+    /// assert!(Guideline::from(Naming::C_CASE).aliases().is_empty());
+    /// ```
+    pub fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Guideline::Naming(Naming::C_ITER_TY) => &["C-ITER-TYPE"],
+            Guideline::Interoperability(Interoperability::C_COMMON_TRAITS) => &["C-COMMON-TRAIT"],
+            Guideline::Interoperability(Interoperability::C_CONV_TRAITS) => &["C-CONV-TRAIT"],
+            Guideline::FutureProofing(FutureProofing::C_STRUCT_BOUNDS) => &["C-STRUCT-BOUND"],
+            Guideline::Macro(Macro::C_MACRO_TY) => &["C-MACRO-TYPE"],
+            _ => &[],
+        }
+    }
+
+    /// Like [`from_id`](Guideline::from_id), but accepts owned or borrowed input such as
+    /// `String` or `Cow<str>`, per [C-GENERIC](crate::Flexibility::C_GENERIC).
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    ///
+    /// use api_guidelines::{Guideline, Naming};
+    ///
+    /// let case = Some(Guideline::from(Naming::C_CASE));
+    /// assert_eq!(Guideline::from_id_like(String::from("C-CASE")), case);
+    /// assert_eq!(Guideline::from_id_like("c-case"), case);
+    /// assert_eq!(Guideline::from_id_like(Cow::Borrowed("C-CASE")), case);
+    /// ```
+    pub fn from_id_like<S: AsRef<str>>(code: S) -> Option<Guideline> {
+        Guideline::from_id(code.as_ref())
+    }
+
+    /// The key to persist the guideline under, e.g. in a database.
+    ///
+    /// Keys are guaranteed to stay the same across releases, even if titles, descriptions, or the
+    /// text produced by other methods change. Today the key is the guideline's code; store it as an
+    /// opaque string all the same, and read it back with
+    /// [`from_stable_key`](Guideline::from_stable_key).
+    ///
+    /// ```rust
+    /// use api_guidelines::Guideline;
+    ///
+    /// for &guideline in Guideline::all() {
+    ///     assert_eq!(Guideline::from_stable_key(guideline.stable_key()), Some(guideline));
+    /// }
+    /// ```
+    pub fn stable_key(self) -> &'static str {
+        self.id()
+    }
+
+    /// The guideline persisted under `key` by [`stable_key`](Guideline::stable_key). Unlike
+    /// [`from_id`](Guideline::from_id), the key must match exactly.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Naming};
+    ///
+    /// assert_eq!(Guideline::from_stable_key("C-CASE"), Some(Naming::C_CASE.into()));
+    /// assert_eq!(Guideline::from_stable_key("c-case"), None);
+    /// ```
+    pub fn from_stable_key(key: &str) -> Option<Guideline> {
+        ALL.iter().copied().find(|g| g.stable_key() == key)
+    }
+
+    /// Returns the known guideline whose code is closest to `code`, if any is within a small edit
+    /// distance. Useful for "did you mean?" hints after [`from_id`](Guideline::from_id) fails.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Naming, TypeSafety};
+    ///
+    /// assert_eq!(Guideline::suggest("C-NEWTYP"), Some(Guideline::from(TypeSafety::C_NEWTYPE)));
+    /// assert_eq!(Guideline::suggest("C-CAES"), Some(Guideline::from(Naming::C_CASE)));
+    /// assert_eq!(Guideline::suggest("definitely not a code"), None);
+    /// ```
+    pub fn suggest(code: &str) -> Option<Guideline> {
+        const MAX_DISTANCE: usize = 2;
+
+        let code = code.trim().to_ascii_uppercase();
+        ALL.iter()
+            .map(|g| (edit_distance(&code, g.id()), *g))
+            .filter(|&(distance, _)| distance <= MAX_DISTANCE)
+            .min_by_key(|&(distance, _)| distance)
+            .map(|(_, g)| g)
+    }
+
+    /// Parses a list of codes separated by commas and/or whitespace, such as
+    /// `"C-CASE, C-CONV, C-NEWTYPE"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error carrying the offending token and its position for the first code that is
+    /// not a known guideline.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, GuidelineSet, Naming, TypeSafety};
+    ///
+    /// let parsed = Guideline::parse_many("C-CASE, C-CONV,C-NEWTYPE")?;
+    /// assert_eq!(
+    ///     parsed,
+    ///     [
+    ///         Guideline::from(Naming::C_CASE),
+    ///         Guideline::from(Naming::C_CONV),
+    ///         Guideline::from(TypeSafety::C_NEWTYPE),
+    ///     ],
+    /// );
+    ///
+    /// let enabled: GuidelineSet = Guideline::parse_many("  C-CASE \t C-CONV  ")?.into_iter().collect();
+    /// assert_eq!(enabled.len(), 2);
+    ///
+    /// let err = Guideline::parse_many("C-CASE, C-BOGUS, C-CONV").unwrap_err();
+    /// assert_eq!((err.token(), err.index()), ("C-BOGUS", Some(1)));
+    /// # Ok::<(), api_guidelines::ParseGuidelineError>(())
+    /// ```
+    pub fn parse_many(input: &str) -> Result<Vec<Guideline>, ParseGuidelineError> {
+        parse_tokens(input).collect()
+    }
+
+    /// Like [`parse_many`](Guideline::parse_many), but keeps going after an unknown code and
+    /// returns the parsed guidelines along with an error for every unknown code, in input order.
+    ///
+    /// ```rust
+    /// use api_guidelines::Guideline;
+    ///
+    /// let (parsed, errors) = Guideline::parse_many_collect("C-CASE, C-BOGUS C-CONV, C-NOPE");
+    /// assert_eq!(parsed.len(), 2);
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!((errors[0].token(), errors[0].index()), ("C-BOGUS", Some(1)));
+    /// assert_eq!((errors[1].token(), errors[1].index()), ("C-NOPE", Some(3)));
+    /// ```
+    pub fn parse_many_collect(input: &str) -> (Vec<Guideline>, Vec<ParseGuidelineError>) {
+        let mut parsed = Vec::new();
+        let mut errors = Vec::new();
+        for result in parse_tokens(input) {
+            match result {
+                Ok(guideline) => parsed.push(guideline),
+                Err(err) => errors.push(err),
+            }
+        }
+        (parsed, errors)
+    }
+
+    /// Dense 0-based position of the guideline within [`Guideline::all`], for indexing lookup
+    /// tables. Stable within a release; [`from_index`](Guideline::from_index) is the inverse.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Naming};
+    ///
+    /// assert_eq!(Guideline::from(Naming::C_CASE).as_index(), 0);
+    /// for (index, guideline) in Guideline::all().iter().enumerate() {
+    ///     assert_eq!(guideline.as_index(), index);
+    ///     assert_eq!(Guideline::from_index(index), Some(*guideline));
+    /// }
+    /// assert_eq!(Guideline::from_index(Guideline::COUNT), None);
+    /// ```
+    pub fn as_index(self) -> usize {
+        CHAPTER_LENS[..self.chapter_index()].iter().sum::<usize>() + self.position_in_chapter()
+    }
+
+    /// The guideline at `index` within [`Guideline::all`], the inverse of
+    /// [`as_index`](Guideline::as_index).
+    pub fn from_index(index: usize) -> Option<Guideline> {
+        ALL.get(index).copied()
+    }
+
+    /// The catalog grouped by category, with categories and the guidelines within each in official
+    /// order.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Category, Guideline, Naming};
+    ///
+    /// let groups = Guideline::grouped_by_category();
+    /// assert_eq!(groups.len(), 11);
+    /// assert_eq!(groups.iter().map(|(_, guidelines)| guidelines.len()).sum::<usize>(), Guideline::COUNT);
+    /// assert_eq!(groups[0].0, Category::Naming);
+    /// assert_eq!(groups[0].1[0], Guideline::from(Naming::C_CASE));
+    /// ```
+    pub fn grouped_by_category() -> Vec<(Category, Vec<Guideline>)> {
+        ALL.chunk_by(|a, b| a.category() == b.category())
+            .map(|chunk| (chunk[0].category(), chunk.to_vec()))
+            .collect()
+    }
+
+    /// Every guideline carrying `tag` among its [`tags`](Guideline::tags), in official order. The
+    /// tag is compared ignoring ASCII case and surrounding whitespace; unknown tags give an empty
+    /// `Vec`.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Interoperability, Naming};
+    ///
+    /// let iterator = Guideline::by_tag("Iterator");
+    /// assert!(iterator.contains(&Naming::C_ITER.into()));
+    /// assert!(iterator.contains(&Naming::C_ITER_TY.into()));
+    /// assert!(iterator.contains(&Interoperability::C_COLLECT.into()));
+    /// assert!(Guideline::by_tag("no-such-tag").is_empty());
+    /// ```
+    pub fn by_tag(tag: &str) -> Vec<Guideline> {
+        let tag = tag.trim();
+        ALL.iter()
+            .copied()
+            .filter(|guideline| guideline.tags().iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            .collect()
+    }
+}
+
+/// Sorts a code table with insertion sort, which is good enough at compile time.
+const fn sort_by_id<const N: usize>(
+    mut table: [(&'static str, Guideline); N],
+) -> [(&'static str, Guideline); N] {
+    let mut i = 1;
+    while i < N {
+        let mut j = i;
+        while j > 0 && is_less(table[j].0, table[j - 1].0) {
+            let entry = table[j];
+            table[j] = table[j - 1];
+            table[j - 1] = entry;
+            j -= 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+/// Byte-wise `a < b`, usable in `const` contexts.
+const fn is_less(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+/// Parses each code of a list separated by commas and/or whitespace, tagging errors with the
+/// token's position.
+fn parse_tokens(input: &str) -> impl Iterator<Item = Result<Guideline, ParseGuidelineError>> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .enumerate()
+        .map(|(index, token)| {
+            token
+                .parse()
+                .map_err(|err: ParseGuidelineError| err.at_index(index))
+        })
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Displays as the category name and the code separated by a slash, e.g. `Naming/C-CASE`, so that
+/// log lines say which chapter a guideline comes from. Use [`Guideline::id`] for the bare code.
+///
+/// ```rust
+/// use api_guidelines::{Guideline, TypeSafety};
+///
+/// let newtype = Guideline::from(TypeSafety::C_NEWTYPE);
+/// assert_eq!(format!("{newtype}"), "TypeSafety/C-NEWTYPE");
+/// assert_eq!(newtype.to_string().parse::<Guideline>()?, newtype);
+/// # Ok::<(), api_guidelines::ParseGuidelineError>(())
+/// ```
+impl fmt::Display for Guideline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.category().name(), self.id())
+    }
+}
+
+/// Parses a code like [`Guideline::from_id`], optionally prefixed with its category as in the
+/// [`Display`](fmt::Display) form. A prefix naming another category is an error.
+impl FromStr for Guideline {
+    type Err = ParseGuidelineError;
+
+    fn from_str(s: &str) -> Result<Guideline, ParseGuidelineError> {
+        let s = s.trim();
+        let guideline = match s.split_once('/') {
+            Some((category, code)) => Guideline::from_id(code.trim())
+                .filter(|g| g.category().name().eq_ignore_ascii_case(category.trim())),
+            None => Guideline::from_id(s),
+        };
+        guideline.ok_or_else(|| ParseGuidelineError::new(s))
+    }
+}
+
+/// Defines the per-guideline tables, and the `phf` map when enabled, from a single list of
+/// chapters and guidelines, so that [`Guideline::all`], the codes, titles, descriptions, tags and
+/// links can't drift apart. The `match`es are exhaustive, so the compiler rejects a list that
+/// misses a variant.
+macro_rules! guidelines {
+    ($(
+        $page:literal => $category:ident {
+            $($variant:ident {
+                id: $id:literal,
+                title: $title:literal,
+                anchor: $anchor:literal,
+                description: $description:literal,
+                tags: [$($tag:literal),* $(,)?] $(,)?
+            })*
+        }
+    )*) => {
+        /// Every guideline, in the order of the official checklist.
+        const ALL: &[Guideline] = &[$($(Guideline::$category($category::$variant),)*)*];
+
+        /// Number of guidelines in each chapter, in chapter order.
+        const CHAPTER_LENS: &[usize] = &[$([$(stringify!($variant)),*].len(),)*];
+
+        /// Every guideline keyed by its code, sorted by code for the binary search in
+        /// [`Guideline::from_id`].
+        const BY_ID: [(&str, Guideline); ALL.len()] =
+            sort_by_id([$($(($id, Guideline::$category($category::$variant)),)*)*]);
+
+        /// Every guideline keyed by its code, for constant-time lookups with exact-case codes.
+        ///
+        /// [`Guideline::from_id`] remains the case-insensitive default.
+        ///
+        /// ```rust
+        /// use api_guidelines::{Guideline, GUIDELINES};
+        ///
+        /// assert_eq!(GUIDELINES.len(), Guideline::COUNT);
+        /// for &guideline in Guideline::all() {
+        ///     assert_eq!(GUIDELINES.get(guideline.id()), Some(&guideline));
+        /// }
+        /// assert_eq!(GUIDELINES.get("c-case"), None);
+        /// ```
+        #[cfg(feature = "phf")]
+        pub static GUIDELINES: phf::Map<&'static str, Guideline> = phf::phf_map! {
+            $($($id => Guideline::$category($category::$variant),)*)*
+        };
+
+        impl Guideline {
+            /// Number of guidelines in the checklist.
+            pub const COUNT: usize = ALL.len();
+
+            /// Returns every guideline in the order of the official checklist.
+            ///
+            /// ```rust
+            /// use api_guidelines::Guideline;
+            ///
+            /// for &guideline in Guideline::all() {
+            ///     assert_eq!(Guideline::from_id(guideline.id()), Some(guideline));
+            /// }
+            /// assert_eq!(Guideline::all().len(), Guideline::COUNT);
+            /// ```
+            pub fn all() -> &'static [Guideline] {
+                ALL
+            }
+
+            /// The guideline's code as used in the official checklist, e.g. `"C-CASE"`.
+            ///
+            /// ```rust
+            /// use api_guidelines::{Guideline, Interoperability};
+            ///
+            /// assert_eq!(Guideline::from(Interoperability::C_SEND_SYNC).id(), "C-SEND-SYNC");
+            /// ```
+            pub fn id(self) -> &'static str {
+                match self {
+                    $($(Guideline::$category($category::$variant) => $id,)*)*
+                }
+            }
+
+            /// The guideline's title as it appears in the official checklist.
+            ///
+            /// ```rust
+            /// use api_guidelines::{Guideline, Naming};
+            ///
+            /// assert_eq!(Guideline::from(Naming::C_CASE).title(), "Casing conforms to RFC 430");
+            /// ```
+            pub fn title(self) -> &'static str {
+                match self {
+                    $($(Guideline::$category($category::$variant) => $title,)*)*
+                }
+            }
+
+            /// A one-sentence English summary of what the guideline asks for.
+            ///
+            /// ```rust
+            /// use api_guidelines::{Guideline, Predictability};
+            ///
+            /// assert_eq!(
+            ///     Guideline::from(Predictability::C_DEREF).description(),
+            ///     "Deref and DerefMut are only implemented by smart pointers.",
+            /// );
+            /// ```
+            pub fn description(self) -> &'static str {
+                match self {
+                    $($(Guideline::$category($category::$variant) => $description,)*)*
+                }
+            }
+
+            /// Topical tags for faceted search, such as `"conversion"`, `"iterator"` or
+            /// `"error-handling"`. Unlike categories, a tag can span several chapters, and a
+            /// guideline can carry more than one. Tags are lowercase and hyphenated.
+            ///
+            /// ```rust
+            /// use api_guidelines::{Guideline, Naming, Predictability};
+            ///
+            /// let conversion: Vec<_> = Guideline::all()
+            ///     .iter()
+            ///     .filter(|guideline| guideline.tags().contains(&"conversion"))
+            ///     .collect();
+            /// assert!(conversion.contains(&&Guideline::from(Naming::C_CONV)));
+            /// assert!(conversion.contains(&&Guideline::from(Predictability::C_CONV_SPECIFIC)));
+            /// ```
+            pub fn tags(self) -> &'static [&'static str] {
+                match self {
+                    $($(Guideline::$category($category::$variant) => &[$($tag),*],)*)*
+                }
+            }
+
+            /// Fragment identifying the guideline within its chapter page, without the leading `#`.
+            ///
+            /// Useful for building links against a local mirror of the guidelines book.
+            ///
+            /// ```rust
+            /// use api_guidelines::{Guideline, Naming};
+            ///
+            /// assert_eq!(Guideline::from(Naming::C_CASE).anchor(), "casing-conforms-to-rfc-430-c-case");
+            /// for guideline in Guideline::all() {
+            ///     assert!(guideline.url().ends_with(&format!("#{}", guideline.anchor())));
+            /// }
+            /// ```
+            pub fn anchor(self) -> &'static str {
+                match self {
+                    $($(Guideline::$category($category::$variant) => $anchor,)*)*
+                }
+            }
+
+            /// Link to the guideline in the official Rust API Guidelines book.
+            ///
+            /// ```rust
+            /// use api_guidelines::{Guideline, Naming};
+            ///
+            /// assert_eq!(
+            ///     Guideline::from(Naming::C_CASE).url(),
+            ///     "https://rust-lang.github.io/api-guidelines/naming.html#casing-conforms-to-rfc-430-c-case",
+            /// );
+            /// ```
+            pub fn url(self) -> &'static str {
+                match self {
+                    $($(Guideline::$category($category::$variant) => concat!(
+                        "https://rust-lang.github.io/api-guidelines/",
+                        $page,
+                        ".html#",
+                        $anchor,
+                    ),)*)*
+                }
+            }
+        }
+    };
+}
+
+guidelines! {
+    "naming" => Naming {
+        C_CASE {
+            id: "C-CASE",
+            title: "Casing conforms to RFC 430",
+            anchor: "casing-conforms-to-rfc-430-c-case",
+            description: "Types and traits use UpperCamelCase, values use snake_case, and constants and statics use SCREAMING_SNAKE_CASE.",
+            tags: ["naming"],
+        }
+        C_CONV {
+            id: "C-CONV",
+            title: "Ad-hoc conversions follow as_, to_, into_ conventions",
+            anchor: "ad-hoc-conversions-follow-as_-to_-into_-conventions-c-conv",
+            description: "Conversion methods are named as_ for free borrowed-to-borrowed views, to_ for expensive conversions and into_ for consuming ones.",
+            tags: ["naming", "conversion"],
+        }
+        C_GETTER {
+            id: "C-GETTER",
+            title: "Getter names follow Rust convention",
+            anchor: "getter-names-follow-rust-convention-c-getter",
+            description: "Getters are named after the field they return, without a get_ prefix, with a _mut variant for mutable access.",
+            tags: ["naming"],
+        }
+        C_ITER {
+            id: "C-ITER",
+            title: "Methods on collections that produce iterators follow iter, iter_mut, into_iter",
+            anchor: "methods-on-collections-that-produce-iterators-follow-iter-iter_mut-into_iter-c-iter",
+            description: "Collections provide iter, iter_mut and into_iter methods yielding &T, &mut T and T respectively.",
+            tags: ["naming", "iterator"],
+        }
+        C_ITER_TY {
+            id: "C-ITER-TY",
+            title: "Iterator type names match the methods that produce them",
+            anchor: "iterator-type-names-match-the-methods-that-produce-them-c-iter-ty",
+            description: "Iterator types are named after the method that produces them, e.g. into_iter returns IntoIter.",
+            tags: ["naming", "iterator"],
+        }
+        C_FEATURE {
+            id: "C-FEATURE",
+            title: "Feature names are free of placeholder words",
+            anchor: "feature-names-are-free-of-placeholder-words-c-feature",
+            description: "Cargo features are named for what they enable, without placeholder words like use- or with-.",
+            tags: ["naming", "cargo"],
+        }
+        C_WORD_ORDER {
+            id: "C-WORD-ORDER",
+            title: "Names use a consistent word order",
+            anchor: "names-use-a-consistent-word-order-c-word-order",
+            description: "Related names use a consistent word order, such as verb-object-error in ParseIntError.",
+            tags: ["naming", "error-handling"],
+        }
+    }
+    "interoperability" => Interoperability {
+        C_COMMON_TRAITS {
+            id: "C-COMMON-TRAITS",
+            title: "Types eagerly implement common traits",
+            anchor: "types-eagerly-implement-common-traits-c-common-traits",
+            description: "Public types eagerly implement the common standard traits like Clone, Debug, PartialEq, Hash and Default where they apply.",
+            tags: ["traits"],
+        }
+        C_CONV_TRAITS {
+            id: "C-CONV-TRAITS",
+            title: "Conversions use the standard traits From, AsRef, AsMut",
+            anchor: "conversions-use-the-standard-traits-from-asref-asmut-c-conv-traits",
+            description: "Conversions are exposed through From, TryFrom, AsRef and AsMut rather than Into or TryInto.",
+            tags: ["traits", "conversion"],
+        }
+        C_COLLECT {
+            id: "C-COLLECT",
+            title: "Collections implement FromIterator and Extend",
+            anchor: "collections-implement-fromiterator-and-extend-c-collect",
+            description: "Collections implement FromIterator and Extend so they work with collect and extend.",
+            tags: ["traits", "iterator"],
+        }
+        C_SERDE {
+            id: "C-SERDE",
+            title: "Data structures implement Serde's Serialize, Deserialize",
+            anchor: "data-structures-implement-serdes-serialize-deserialize-c-serde",
+            description: "Data structures implement serde's Serialize and Deserialize, usually behind a cargo feature.",
+            tags: ["traits", "serialization"],
+        }
+        C_SEND_SYNC {
+            id: "C-SEND-SYNC",
+            title: "Types are Send and Sync where possible",
+            anchor: "types-are-send-and-sync-where-possible-c-send-sync",
+            description: "Types are Send and Sync where possible, and tests guard against losing those impls.",
+            tags: ["traits", "threading"],
+        }
+        C_GOOD_ERR {
+            id: "C-GOOD-ERR",
+            title: "Error types are meaningful and well-behaved",
+            anchor: "error-types-are-meaningful-and-well-behaved-c-good-err",
+            description: "Error types implement std::error::Error, are Send and Sync, and have meaningful Display messages.",
+            tags: ["error-handling", "threading"],
+        }
+        C_NUM_FMT {
+            id: "C-NUM-FMT",
+            title: "Binary number types provide Hex, Octal, Binary formatting",
+            anchor: "binary-number-types-provide-hex-octal-binary-formatting-c-num-fmt",
+            description: "Binary number types implement the formatting traits UpperHex, LowerHex, Octal and Binary.",
+            tags: ["traits", "formatting"],
+        }
+        C_RW_VALUE {
+            id: "C-RW-VALUE",
+            title: "Generic reader/writer functions take R: Read and W: Write by value",
+            anchor: "generic-readerwriter-functions-take-r-read-and-w-write-by-value-c-rw-value",
+            description: "Generic functions taking a reader or writer take it by value, since &mut R is itself Read.",
+            tags: ["generics", "io"],
+        }
+    }
+    "predictability" => Predictability {
+        C_SMART_PTR {
+            id: "C-SMART-PTR",
+            title: "Smart pointers do not add inherent methods",
+            anchor: "smart-pointers-do-not-add-inherent-methods-c-smart-ptr",
+            description: "Smart pointers do not add inherent methods, using associated functions instead to avoid confusion with the target's methods.",
+            tags: ["smart-pointers"],
+        }
+        C_CONV_SPECIFIC {
+            id: "C-CONV-SPECIFIC",
+            title: "Conversions live on the most specific type involved",
+            anchor: "conversions-live-on-the-most-specific-type-involved-c-conv-specific",
+            description: "A conversion lives on the more specific of the two types involved.",
+            tags: ["conversion"],
+        }
+        C_METHOD {
+            id: "C-METHOD",
+            title: "Functions with a clear receiver are methods",
+            anchor: "functions-with-a-clear-receiver-are-methods-c-method",
+            description: "Functions with a clear receiver are methods rather than free functions.",
+            tags: ["methods"],
+        }
+        C_NO_OUT {
+            id: "C-NO-OUT",
+            title: "Functions do not take out-parameters",
+            anchor: "functions-do-not-take-out-parameters-c-no-out",
+            description: "Functions return their results rather than writing them through out-parameters.",
+            tags: ["methods"],
+        }
+        C_OVERLOAD {
+            id: "C-OVERLOAD",
+            title: "Operator overloads are unsurprising",
+            anchor: "operator-overloads-are-unsurprising-c-overload",
+            description: "Operator overloads only implement operations that resemble the operator's usual meaning.",
+            tags: ["traits", "operators"],
+        }
+        C_DEREF {
+            id: "C-DEREF",
+            title: "Only smart pointers implement Deref and DerefMut",
+            anchor: "only-smart-pointers-implement-deref-and-derefmut-c-deref",
+            description: "Deref and DerefMut are only implemented by smart pointers.",
+            tags: ["traits", "smart-pointers"],
+        }
+        C_CTOR {
+            id: "C-CTOR",
+            title: "Constructors are static, inherent methods",
+            anchor: "constructors-are-static-inherent-methods-c-ctor",
+            description: "Constructors are static inherent methods, conventionally named new or with_ and from_ variants.",
+            tags: ["construction"],
+        }
+    }
+    "flexibility" => Flexibility {
+        C_INTERMEDIATE {
+            id: "C-INTERMEDIATE",
+            title: "Functions expose intermediate results to avoid duplicate work",
+            anchor: "functions-expose-intermediate-results-to-avoid-duplicate-work-c-intermediate",
+            description: "Functions expose intermediate results they compute anyway when callers may find them useful.",
+            tags: ["methods"],
+        }
+        C_CALLER_CONTROL {
+            id: "C-CALLER-CONTROL",
+            title: "Caller decides where to copy and place data",
+            anchor: "caller-decides-where-to-copy-and-place-data-c-caller-control",
+            description: "Functions take ownership of arguments they need to own, letting the caller decide when to clone.",
+            tags: ["ownership"],
+        }
+        C_GENERIC {
+            id: "C-GENERIC",
+            title: "Functions minimize assumptions about parameters by using generics",
+            anchor: "functions-minimize-assumptions-about-parameters-by-using-generics-c-generic",
+            description: "Functions accept generic parameters to minimize assumptions about their inputs.",
+            tags: ["generics"],
+        }
+        C_OBJECT {
+            id: "C-OBJECT",
+            title: "Traits are object-safe if they may be useful as a trait object",
+            anchor: "traits-are-object-safe-if-they-may-be-useful-as-a-trait-object-c-object",
+            description: "Traits that may be useful as trait objects are kept object-safe.",
+            tags: ["traits", "generics"],
+        }
+    }
+    "type-safety" => TypeSafety {
+        C_NEWTYPE {
+            id: "C-NEWTYPE",
+            title: "Newtypes provide static distinctions",
+            anchor: "newtypes-provide-static-distinctions-c-newtype",
+            description: "Newtypes distinguish values that share an underlying type but differ in meaning.",
+            tags: ["types"],
+        }
+        C_CUSTOM_TYPE {
+            id: "C-CUSTOM-TYPE",
+            title: "Arguments convey meaning through types, not bool or Option",
+            anchor: "arguments-convey-meaning-through-types-not-bool-or-option-c-custom-type",
+            description: "Arguments convey their meaning through dedicated types rather than bool or Option.",
+            tags: ["types"],
+        }
+        C_BITFLAG {
+            id: "C-BITFLAG",
+            title: "Types for a set of flags are bitflags, not enums",
+            anchor: "types-for-a-set-of-flags-are-bitflags-not-enums-c-bitflag",
+            description: "Sets of flags are represented with the bitflags crate rather than enums.",
+            tags: ["types"],
+        }
+        C_BUILDER {
+            id: "C-BUILDER",
+            title: "Builders enable construction of complex values",
+            anchor: "builders-enable-construction-of-complex-values-c-builder",
+            description: "Complex values are constructed with builders.",
+            tags: ["construction"],
+        }
+    }
+    "dependability" => Dependability {
+        C_VALIDATE {
+            id: "C-VALIDATE",
+            title: "Functions validate their arguments",
+            anchor: "functions-validate-their-arguments-c-validate",
+            description: "Functions validate their arguments, statically where possible, instead of accepting anything.",
+            tags: ["error-handling"],
+        }
+        C_DTOR_FAIL {
+            id: "C-DTOR-FAIL",
+            title: "Destructors never fail",
+            anchor: "destructors-never-fail-c-dtor-fail",
+            description: "Destructors never fail; fallible cleanup goes in a separate method.",
+            tags: ["destructors", "error-handling"],
+        }
+        C_DTOR_BLOCK {
+            id: "C-DTOR-BLOCK",
+            title: "Destructors that may block have alternatives",
+            anchor: "destructors-that-may-block-have-alternatives-c-dtor-block",
+            description: "Destructors that may block offer a separate method to tear down without blocking.",
+            tags: ["destructors"],
+        }
+    }
+    "debuggability" => Debuggability {
+        C_DEBUG {
+            id: "C-DEBUG",
+            title: "All public types implement Debug",
+            anchor: "all-public-types-implement-debug-c-debug",
+            description: "All public types implement Debug.",
+            tags: ["traits", "formatting"],
+        }
+        C_DEBUG_NONEMPTY {
+            id: "C-DEBUG-NONEMPTY",
+            title: "Debug representation is never empty",
+            anchor: "debug-representation-is-never-empty-c-debug-nonempty",
+            description: "Debug output is never empty, even for empty values.",
+            tags: ["formatting"],
+        }
+    }
+    "future-proofing" => FutureProofing {
+        C_SEALED {
+            id: "C-SEALED",
+            title: "Sealed traits protect against downstream implementations",
+            anchor: "sealed-traits-protect-against-downstream-implementations-c-sealed",
+            description: "Traits only meant to be implemented within their crate are sealed so they can evolve without breaking changes.",
+            tags: ["traits", "stability"],
+        }
+        C_STRUCT_PRIVATE {
+            id: "C-STRUCT-PRIVATE",
+            title: "Structs have private fields",
+            anchor: "structs-have-private-fields-c-struct-private",
+            description: "Structs keep their fields private unless they are passive data without invariants.",
+            tags: ["stability"],
+        }
+        C_NEWTYPE_HIDE {
+            id: "C-NEWTYPE-HIDE",
+            title: "Newtypes encapsulate implementation details",
+            anchor: "newtypes-encapsulate-implementation-details-c-newtype-hide",
+            description: "Newtypes hide implementation details, such as a complex iterator type, behind a precise promise.",
+            tags: ["types", "stability"],
+        }
+        C_STRUCT_BOUNDS {
+            id: "C-STRUCT-BOUNDS",
+            title: "Data structures do not duplicate derived trait bounds",
+            anchor: "data-structures-do-not-duplicate-derived-trait-bounds-c-struct-bounds",
+            description: "Data structures do not put derivable trait bounds on their type parameters.",
+            tags: ["generics", "stability"],
+        }
+    }
+    "necessities" => Necessities {
+        C_STABLE {
+            id: "C-STABLE",
+            title: "Public dependencies of a stable crate are stable",
+            anchor: "public-dependencies-of-a-stable-crate-are-stable-c-stable",
+            description: "A stable crate only has stable public dependencies.",
+            tags: ["dependencies", "stability"],
+        }
+        C_PERMISSIVE {
+            id: "C-PERMISSIVE",
+            title: "Crate and its dependencies have a permissive license",
+            anchor: "crate-and-its-dependencies-have-a-permissive-license-c-permissive",
+            description: "Crates and their dependencies are licensed permissively, typically as MIT OR Apache-2.0.",
+            tags: ["dependencies", "licensing"],
+        }
+    }
+    "documentation" => Documentation {
+        C_CRATE_DOC {
+            id: "C-CRATE-DOC",
+            title: "Crate level docs are thorough and include examples",
+            anchor: "crate-level-docs-are-thorough-and-include-examples-c-crate-doc",
+            description: "The crate-level documentation is thorough and includes examples.",
+            tags: ["docs"],
+        }
+        C_EXAMPLE {
+            id: "C-EXAMPLE",
+            title: "All items have a rustdoc example",
+            anchor: "all-items-have-a-rustdoc-example-c-example",
+            description: "Every public item has a rustdoc example showing why and how to use it.",
+            tags: ["docs"],
+        }
+        C_QUESTION_MARK {
+            id: "C-QUESTION-MARK",
+            title: "Examples use ?, not try!, not unwrap",
+            anchor: "examples-use--not-try-not-unwrap-c-question-mark",
+            description: "Examples propagate errors with ? rather than unwrap or try!.",
+            tags: ["docs", "error-handling"],
+        }
+        C_FAILURE {
+            id: "C-FAILURE",
+            title: "Function docs include error, panic, and safety considerations",
+            anchor: "function-docs-include-error-panic-and-safety-considerations-c-failure",
+            description: "Function docs include Errors, Panics and Safety sections where relevant.",
+            tags: ["docs", "error-handling"],
+        }
+        C_LINK {
+            id: "C-LINK",
+            title: "Prose contains hyperlinks to relevant things",
+            anchor: "prose-contains-hyperlinks-to-relevant-things-c-link",
+            description: "Prose links to the relevant types, methods and external resources.",
+            tags: ["docs"],
+        }
+        C_METADATA {
+            id: "C-METADATA",
+            title: "Cargo.toml includes all common metadata",
+            anchor: "cargotoml-includes-all-common-metadata-c-metadata",
+            description: "Cargo.toml includes the common package metadata: authors, description, license, repository, keywords and categories.",
+            tags: ["docs", "cargo"],
+        }
+        C_RELNOTES {
+            id: "C-RELNOTES",
+            title: "Release notes document all significant changes",
+            anchor: "release-notes-document-all-significant-changes-c-relnotes",
+            description: "Release notes document every significant change, and releases are tagged in the repository.",
+            tags: ["docs"],
+        }
+        C_HIDDEN {
+            id: "C-HIDDEN",
+            title: "Rustdoc does not show unhelpful implementation details",
+            anchor: "rustdoc-does-not-show-unhelpful-implementation-details-c-hidden",
+            description: "Rustdoc does not show unhelpful implementation details.",
+            tags: ["docs"],
+        }
+    }
+    "macros" => Macro {
+        C_EVOCATIVE {
+            id: "C-EVOCATIVE",
+            title: "Input syntax is evocative of the output",
+            anchor: "input-syntax-is-evocative-of-the-output-c-evocative",
+            description: "Macro input syntax mirrors the Rust syntax of the output it produces.",
+            tags: ["macros"],
+        }
+        C_MACRO_ATTR {
+            id: "C-MACRO-ATTR",
+            title: "Item macros compose well with attributes",
+            anchor: "item-macros-compose-well-with-attributes-c-macro-attr",
+            description: "Item macros accept attributes, such as doc comments and cfg, on the items they produce.",
+            tags: ["macros"],
+        }
+        C_ANYWHERE {
+            id: "C-ANYWHERE",
+            title: "Item macros work anywhere that items are allowed",
+            anchor: "item-macros-work-anywhere-that-items-are-allowed-c-anywhere",
+            description: "Item macros work anywhere items are allowed, including function scope.",
+            tags: ["macros"],
+        }
+        C_MACRO_VIS {
+            id: "C-MACRO-VIS",
+            title: "Item macros support visibility specifiers",
+            anchor: "item-macros-support-visibility-specifiers-c-macro-vis",
+            description: "Item macros support a pub specifier and produce private items by default.",
+            tags: ["macros"],
+        }
+        C_MACRO_TY {
+            id: "C-MACRO-TY",
+            title: "Type fragments are flexible",
+            anchor: "type-fragments-are-flexible-c-macro-ty",
+            description: "Macros accepting a ty fragment handle primitives, paths, generics and other type forms.",
+            tags: ["macros", "types"],
+        }
+    }
+}
+
+/// A category of guidelines, corresponding to a chapter of the guidelines book and to one of the
+/// category enums such as [`Naming`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category {
+    Naming,
+    Interoperability,
+    Predictability,
+    Flexibility,
+    TypeSafety,
+    Dependability,
+    Debuggability,
+    FutureProofing,
+    Necessities,
+    Documentation,
+    Macro,
+}
+
+impl Category {
+    /// Every category, in the chapter order of the guidelines book.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Category, Guideline};
+    ///
+    /// assert_eq!(Category::ALL.len(), Guideline::grouped_by_category().len());
+    /// for category in Category::ALL {
+    ///     assert_eq!(category.to_string().parse::<Category>()?, category);
+    /// }
+    /// # Ok::<(), api_guidelines::ParseCategoryError>(())
+    /// ```
+    pub const ALL: [Category; 11] = [
+        Category::Naming,
+        Category::Interoperability,
+        Category::Predictability,
+        Category::Flexibility,
+        Category::TypeSafety,
+        Category::Dependability,
+        Category::Debuggability,
+        Category::FutureProofing,
+        Category::Necessities,
+        Category::Documentation,
+        Category::Macro,
+    ];
+
+    /// Name of the category, matching the name of its enum, e.g. `"TypeSafety"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Category::Naming => "Naming",
+            Category::Interoperability => "Interoperability",
+            Category::Predictability => "Predictability",
+            Category::Flexibility => "Flexibility",
+            Category::TypeSafety => "TypeSafety",
+            Category::Dependability => "Dependability",
+            Category::Debuggability => "Debuggability",
+            Category::FutureProofing => "FutureProofing",
+            Category::Necessities => "Necessities",
+            Category::Documentation => "Documentation",
+            Category::Macro => "Macro",
+        }
+    }
+}
+
+/// Displays the category's [`name`](Category::name).
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a category [`name`](Category::name), ignoring ASCII case.
+impl FromStr for Category {
+    type Err = ParseCategoryError;
+
+    fn from_str(s: &str) -> Result<Category, ParseCategoryError> {
+        let s = s.trim();
+        Category::ALL
+            .into_iter()
+            .find(|category| category.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParseCategoryError::new(s))
+    }
+}
+
+/// How serious a violation of a guideline is, see [`Guideline::severity`].
+///
+/// Ordered from least to most serious, so `severity >= Severity::Warning` selects warnings and
+/// errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Note,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// A one-character marker for terminal output: `ℹ`, `⚠` or `✖`.
+    ///
+    /// Without the default `unicode` feature this is the [`ascii_glyph`](Severity::ascii_glyph)
+    /// instead, for terminals that can't display those characters.
+    ///
+    /// ```rust
+    /// use api_guidelines::Severity;
+    ///
+    /// let glyphs = [Severity::Note, Severity::Warning, Severity::Error].map(Severity::glyph);
+    /// assert!(glyphs.iter().all(|glyph| !glyph.is_empty()));
+    /// assert!(glyphs[0] != glyphs[1] && glyphs[1] != glyphs[2] && glyphs[0] != glyphs[2]);
+    /// ```
+    pub fn glyph(self) -> &'static str {
+        if cfg!(feature = "unicode") {
+            match self {
+                Severity::Note => "ℹ",
+                Severity::Warning => "⚠",
+                Severity::Error => "✖",
+            }
+        } else {
+            self.ascii_glyph()
+        }
+    }
+
+    /// The ASCII fallback of [`glyph`](Severity::glyph): `i`, `!` or `x`.
+    ///
+    /// ```rust
+    /// use api_guidelines::Severity;
+    ///
+    /// let glyphs = [Severity::Note, Severity::Warning, Severity::Error].map(Severity::ascii_glyph);
+    /// assert!(glyphs.iter().all(|glyph| !glyph.is_empty() && glyph.is_ascii()));
+    /// assert!(glyphs[0] != glyphs[1] && glyphs[1] != glyphs[2] && glyphs[0] != glyphs[2]);
+    /// ```
+    pub fn ascii_glyph(self) -> &'static str {
+        match self {
+            Severity::Note => "i",
+            Severity::Warning => "!",
+            Severity::Error => "x",
+        }
+    }
+}
+
+/// What a guideline is mainly about, a coarser axis than [`Category`], see [`Guideline::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GuidelineKind {
+    /// Breaking it can make code unsound, hang or misbehave at runtime: thread safety, argument
+    /// validation and destructors.
+    Correctness,
+    /// It lets the crate work with the rest of the ecosystem: common traits, error types, `Debug`,
+    /// stable dependencies and licensing.
+    Interop,
+    /// It shapes how pleasant and future-proof the API is to use: predictability, flexibility,
+    /// type safety, future proofing and most macro guidelines.
+    Ergonomics,
+    /// It is about the crate's documentation and metadata.
+    Documentation,
+    /// It is about naming and surface syntax.
+    Style,
+}
+
+/// Defines the `is_*` predicates of [`Guideline`], one per category.
+macro_rules! category_predicates {
+    ($($predicate:ident => $category:ident,)*) => {
+        /// Per-category predicates, consistent with [`Guideline::category`]. They take `&self` so
+        /// they can be passed straight to [`Iterator::filter`].
+        ///
+        /// ```rust
+        /// use api_guidelines::{Documentation, Guideline};
+        ///
+        /// let docs: Vec<Guideline> = Guideline::all()
+        ///     .iter()
+        ///     .copied()
+        ///     .filter(Guideline::is_documentation)
+        ///     .collect();
+        /// assert_eq!(docs.first(), Some(&Guideline::from(Documentation::C_CRATE_DOC)));
+        ///
+        /// for g in Guideline::all() {
+        ///     let predicates = [
+        ///         g.is_naming(),
+        ///         g.is_interoperability(),
+        ///         g.is_predictability(),
+        ///         g.is_flexibility(),
+        ///         g.is_type_safety(),
+        ///         g.is_dependability(),
+        ///         g.is_debuggability(),
+        ///         g.is_future_proofing(),
+        ///         g.is_necessities(),
+        ///         g.is_documentation(),
+        ///         g.is_macro(),
+        ///     ];
+        ///     assert_eq!(predicates.iter().filter(|&&p| p).count(), 1);
+        ///     assert!(predicates[g.chapter_index()]);
+        /// }
+        /// ```
+        impl Guideline {
+            $(
+                #[doc = concat!("Returns whether the guideline is in the [`", stringify!($category), "`] category.")]
+                pub fn $predicate(&self) -> bool {
+                    matches!(self, Guideline::$category(_))
+                }
+            )*
+        }
+    };
+}
+
+category_predicates! {
+    is_naming => Naming,
+    is_interoperability => Interoperability,
+    is_predictability => Predictability,
+    is_flexibility => Flexibility,
+    is_type_safety => TypeSafety,
+    is_dependability => Dependability,
+    is_debuggability => Debuggability,
+    is_future_proofing => FutureProofing,
+    is_necessities => Necessities,
+    is_documentation => Documentation,
+    is_macro => Macro,
+}
+
+macro_rules! impl_from_category {
+    ($($category:ident),* $(,)?) => {
+        $(
+            impl From<$category> for Guideline {
+                fn from(guideline: $category) -> Guideline {
+                    Guideline::$category(guideline)
+                }
+            }
+
+            impl TryFrom<Guideline> for $category {
+                type Error = CategoryMismatchError;
+
+                fn try_from(guideline: Guideline) -> Result<$category, CategoryMismatchError> {
+                    match guideline {
+                        Guideline::$category(inner) => Ok(inner),
+                        _ => Err(CategoryMismatchError::new(guideline, Category::$category)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_category!(
+    Naming,
+    Interoperability,
+    Predictability,
+    Flexibility,
+    TypeSafety,
+    Dependability,
+    Debuggability,
+    FutureProofing,
+    Necessities,
+    Documentation,
+    Macro,
+);
+
+/// Gives each category enum the per-guideline methods of [`Guideline`] that make sense without
+/// converting first.
+macro_rules! impl_category_methods {
+    ($($category:ident),* $(,)?) => {
+        $(
+            impl $category {
+                /// See [`Guideline::min_rust_version`].
+                pub fn min_rust_version(self) -> Option<&'static str> {
+                    Guideline::from(self).min_rust_version()
+                }
+
+                /// See [`Guideline::severity`].
+                pub fn severity(self) -> Severity {
+                    Guideline::from(self).severity()
+                }
+
+                /// See [`Guideline::example_snippets`].
+                pub fn example_snippets(self) -> &'static [Snippet] {
+                    Guideline::from(self).example_snippets()
+                }
+            }
+        )*
+    };
+}
+
+impl_category_methods!(
+    Naming,
+    Interoperability,
+    Predictability,
+    Flexibility,
+    TypeSafety,
+    Dependability,
+    Debuggability,
+    FutureProofing,
+    Necessities,
+    Documentation,
+    Macro,
+);
+
+/// Checks the catalog for internal inconsistencies, returning a description of each problem.
+///
+/// Verifies that codes are unique, that each URL is an absolute `https` link ending with the
+/// guideline's anchor, that [`Guideline::from_id`] and [`Guideline::from_index`] round-trip, that
+/// aliases resolve to their guideline, that [`Guideline::all`] and the code lookup table are
+/// sorted, and that [`Category::ALL`] lists every chapter in order. Meant as a self-test to run in
+/// CI after upgrading.
+///
+/// ```rust
+/// assert_eq!(api_guidelines::validate_catalog_integrity(), Ok(()));
+/// ```
+pub fn validate_catalog_integrity() -> Result<(), Vec<String>> {
+    let mut problems = Vec::new();
+    for (index, &guideline) in ALL.iter().enumerate() {
+        let id = guideline.id();
+        if ALL[..index].iter().any(|other| other.id() == id) {
+            problems.push(format!("duplicate code '{id}'"));
+        }
+        let url = guideline.url();
+        if !url.starts_with("https://") || !url.ends_with(&format!("#{}", guideline.anchor())) {
+            problems.push(format!("'{id}' has malformed url '{url}'"));
+        }
+        if Guideline::from_id(id) != Some(guideline) {
+            problems.push(format!("'{id}' does not round-trip through from_id"));
+        }
+        for alias in guideline.aliases() {
+            if Guideline::from_id(alias) != Some(guideline) {
+                problems.push(format!("alias '{alias}' of '{id}' does not resolve to it"));
+            }
+        }
+        if guideline.as_index() != index || Guideline::from_index(index) != Some(guideline) {
+            problems.push(format!("'{id}' does not round-trip through as_index"));
+        }
+        if index > 0 && ALL[index - 1] >= guideline {
+            problems.push(format!("'{id}' is out of order"));
+        }
+    }
+    let chapters: Vec<Category> = Guideline::grouped_by_category()
+        .into_iter()
+        .map(|(category, _)| category)
+        .collect();
+    if chapters != Category::ALL {
+        problems.push("Category::ALL does not list the chapters in order".to_owned());
+    }
+    if !BY_ID.is_sorted_by(|a, b| a.0 < b.0) {
+        problems.push("the code lookup table is not strictly sorted".to_owned());
+    }
+    if Guideline::from_index(ALL.len()).is_some() {
+        problems.push("from_index accepts an index past the end".to_owned());
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems)
+    }
+}
@@ -0,0 +1,226 @@
+//! Localized guideline text, enabled by the `i18n` feature.
+//!
+//! English is the baseline and comes from [`Guideline::title`] and [`Guideline::description`].
+//! Every other language is a table of `(id, text)` pairs below; guidelines missing from a table
+//! fall back to English, so a language can be added one guideline at a time.
+
+use std::borrow::Cow;
+
+use crate::Guideline;
+
+/// A language guideline text can be localized into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[non_exhaustive]
+pub enum Lang {
+    #[default]
+    En,
+    /// Japanese. Every title is translated; descriptions fall back to English for now.
+    Ja,
+}
+
+impl Lang {
+    /// Translated titles, keyed by guideline id.
+    fn titles(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Lang::En => &[],
+            Lang::Ja => JA_TITLES,
+        }
+    }
+
+    /// Translated descriptions, keyed by guideline id.
+    fn descriptions(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Lang::En => &[],
+            Lang::Ja => JA_DESCRIPTIONS,
+        }
+    }
+}
+
+/// Japanese titles, in official order.
+const JA_TITLES: &[(&str, &str)] = &[
+    ("C-CASE", "大文字小文字の使い分けが RFC 430 に従っている"),
+    (
+        "C-CONV",
+        "アドホックな変換が as_、to_、into_ の慣例に従っている",
+    ),
+    ("C-GETTER", "ゲッターの名前が Rust の慣例に従っている"),
+    (
+        "C-ITER",
+        "イテレータを生成するコレクションのメソッドが iter、iter_mut、into_iter に従っている",
+    ),
+    (
+        "C-ITER-TY",
+        "イテレータの型名が、それを生成するメソッドと一致している",
+    ),
+    ("C-FEATURE", "フィーチャー名に余計な単語が含まれていない"),
+    ("C-WORD-ORDER", "名前に含まれる単語の順序が一貫している"),
+    (
+        "C-COMMON-TRAITS",
+        "型は一般的なトレイトを積極的に実装している",
+    ),
+    (
+        "C-CONV-TRAITS",
+        "変換に標準のトレイト From、AsRef、AsMut を用いている",
+    ),
+    (
+        "C-COLLECT",
+        "コレクションが FromIterator と Extend を実装している",
+    ),
+    (
+        "C-SERDE",
+        "データ構造が Serde の Serialize と Deserialize を実装している",
+    ),
+    ("C-SEND-SYNC", "型は可能な限り Send と Sync である"),
+    ("C-GOOD-ERR", "エラー型は意味のある、行儀の良いものである"),
+    (
+        "C-NUM-FMT",
+        "バイナリ数値型が Hex、Octal、Binary のフォーマットを提供している",
+    ),
+    (
+        "C-RW-VALUE",
+        "読み書きを行うジェネリックな関数は R: Read と W: Write を値で受け取る",
+    ),
+    ("C-SMART-PTR", "スマートポインタは固有メソッドを追加しない"),
+    (
+        "C-CONV-SPECIFIC",
+        "変換は関係する型のうち最も具体的な型に定義する",
+    ),
+    ("C-METHOD", "明確なレシーバを持つ関数はメソッドである"),
+    ("C-NO-OUT", "関数は出力用の引数を取らない"),
+    ("C-OVERLOAD", "演算子のオーバーロードは驚きのないものである"),
+    (
+        "C-DEREF",
+        "Deref と DerefMut を実装しているのはスマートポインタだけである",
+    ),
+    ("C-CTOR", "コンストラクタはスタティックな固有メソッドである"),
+    (
+        "C-INTERMEDIATE",
+        "関数は重複した処理を避けるため中間結果を公開する",
+    ),
+    (
+        "C-CALLER-CONTROL",
+        "データをコピーする場所と配置する場所は呼び出し側が決める",
+    ),
+    (
+        "C-GENERIC",
+        "関数はジェネリクスを用いて引数に対する仮定を最小限にする",
+    ),
+    (
+        "C-OBJECT",
+        "トレイトオブジェクトとして有用なトレイトはオブジェクト安全である",
+    ),
+    ("C-NEWTYPE", "newtype で静的な区別を提供する"),
+    (
+        "C-CUSTOM-TYPE",
+        "引数は bool や Option ではなく型によって意味を伝える",
+    ),
+    (
+        "C-BITFLAG",
+        "フラグの集合を表す型は列挙型ではなく bitflags である",
+    ),
+    ("C-BUILDER", "複雑な値の生成にビルダーを用いる"),
+    ("C-VALIDATE", "関数は引数を検証する"),
+    ("C-DTOR-FAIL", "デストラクタは失敗しない"),
+    (
+        "C-DTOR-BLOCK",
+        "ブロックする可能性のあるデストラクタには代替手段を用意する",
+    ),
+    ("C-DEBUG", "すべての公開型が Debug を実装している"),
+    ("C-DEBUG-NONEMPTY", "Debug 表現が空になることはない"),
+    ("C-SEALED", "シールドトレイトで下流クレートによる実装を防ぐ"),
+    ("C-STRUCT-PRIVATE", "構造体のフィールドはプライベートである"),
+    ("C-NEWTYPE-HIDE", "newtype で実装の詳細をカプセル化する"),
+    (
+        "C-STRUCT-BOUNDS",
+        "データ構造が derive したトレイトの境界を重複して指定しない",
+    ),
+    ("C-STABLE", "安定版クレートの公開依存クレートは安定版である"),
+    (
+        "C-PERMISSIVE",
+        "クレートとその依存クレートが寛容なライセンスを持つ",
+    ),
+    (
+        "C-CRATE-DOC",
+        "クレートレベルのドキュメントが充実しており、例を含んでいる",
+    ),
+    ("C-EXAMPLE", "すべてのアイテムに rustdoc の例がある"),
+    ("C-QUESTION-MARK", "例では try! や unwrap ではなく ? を使う"),
+    (
+        "C-FAILURE",
+        "関数のドキュメントにエラー、パニック、安全性についての考察が含まれている",
+    ),
+    ("C-LINK", "文章に関連項目へのハイパーリンクが含まれている"),
+    (
+        "C-METADATA",
+        "Cargo.toml に一般的なメタデータがすべて含まれている",
+    ),
+    (
+        "C-RELNOTES",
+        "リリースノートにすべての重要な変更が記載されている",
+    ),
+    ("C-HIDDEN", "rustdoc に役に立たない実装の詳細が表示されない"),
+    ("C-EVOCATIVE", "入力の構文から出力が想起できる"),
+    (
+        "C-MACRO-ATTR",
+        "アイテムを生成するマクロが属性とうまく組み合わさる",
+    ),
+    (
+        "C-ANYWHERE",
+        "アイテムを生成するマクロがアイテムを置ける場所ならどこでも動作する",
+    ),
+    (
+        "C-MACRO-VIS",
+        "アイテムを生成するマクロが可視性の指定をサポートする",
+    ),
+    ("C-MACRO-TY", "型のフラグメントが柔軟である"),
+];
+
+/// Japanese descriptions. Contributions welcome.
+const JA_DESCRIPTIONS: &[(&str, &str)] = &[];
+
+impl Guideline {
+    /// The guideline's title in `lang`, falling back to the English [`title`](Guideline::title)
+    /// when no translation exists.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Naming};
+    /// use api_guidelines::i18n::Lang;
+    ///
+    /// let guideline = Guideline::from(Naming::C_CASE);
+    /// assert_eq!(guideline.title_localized(Lang::En), guideline.title());
+    /// assert_eq!(guideline.title_localized(Lang::Ja), "大文字小文字の使い分けが RFC 430 に従っている");
+    /// assert!(Guideline::all().iter().all(|g| g.title_localized(Lang::Ja) != g.title()));
+    /// ```
+    pub fn title_localized(self, lang: Lang) -> &'static str {
+        lang.titles()
+            .iter()
+            .find(|(id, _)| *id == self.id())
+            .map_or_else(|| self.title(), |(_, title)| title)
+    }
+
+    /// The guideline's [`description`](Guideline::description) in `lang`, falling back to English
+    /// when no translation exists.
+    ///
+    /// Every description is static text today, so this never allocates; the `Cow` leaves room for
+    /// translations that have to be assembled at runtime.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    ///
+    /// use api_guidelines::{Guideline, Naming};
+    /// use api_guidelines::i18n::Lang;
+    ///
+    /// let guideline = Guideline::from(Naming::C_CASE);
+    /// let description = guideline.description_localized(Lang::En);
+    /// assert!(matches!(description, Cow::Borrowed(_)));
+    /// assert_eq!(description, guideline.description());
+    /// assert_eq!(guideline.description_localized(Lang::Ja), guideline.description());
+    /// ```
+    pub fn description_localized(self, lang: Lang) -> Cow<'static, str> {
+        lang.descriptions()
+            .iter()
+            .find(|(id, _)| *id == self.id())
+            .map_or_else(|| self.description(), |(_, description)| description)
+            .into()
+    }
+}
@@ -0,0 +1,206 @@
+//! Helpers for applying the [`Interoperability`] guidelines.
+
+pub use crate::Interoperability;
+
+use crate::Violation;
+
+/// Which of the [C-NUM-FMT](crate::Interoperability::C_NUM_FMT) formatting traits a binary number
+/// type implements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NumFmtImpls {
+    pub upper_hex: bool,
+    pub lower_hex: bool,
+    pub octal: bool,
+    pub binary: bool,
+}
+
+/// Returns the names of the number formatting traits a binary number type still has to implement.
+///
+/// ```rust
+/// use api_guidelines::interoperability::{missing_num_fmt, NumFmtImpls};
+///
+/// let impls = NumFmtImpls { lower_hex: true, ..NumFmtImpls::default() };
+/// assert_eq!(missing_num_fmt(&impls), ["UpperHex", "Octal", "Binary"]);
+///
+/// let all = NumFmtImpls { upper_hex: true, lower_hex: true, octal: true, binary: true };
+/// assert!(missing_num_fmt(&all).is_empty());
+/// ```
+pub fn missing_num_fmt(impls: &NumFmtImpls) -> Vec<&'static str> {
+    [
+        (impls.upper_hex, "UpperHex"),
+        (impls.lower_hex, "LowerHex"),
+        (impls.octal, "Octal"),
+        (impls.binary, "Binary"),
+    ]
+    .into_iter()
+    .filter(|&(implemented, _)| !implemented)
+    .map(|(_, name)| name)
+    .collect()
+}
+
+/// Returns which of `FromIterator` and `Extend` a collection type still has to implement, per
+/// [C-COLLECT](crate::Interoperability::C_COLLECT).
+///
+/// Types that are not conceptually collections have nothing to implement.
+///
+/// ```rust
+/// use api_guidelines::interoperability::check_collect;
+///
+/// assert_eq!(check_collect(true, false, true), ["Extend"]);
+/// assert_eq!(check_collect(false, false, true), ["FromIterator", "Extend"]);
+/// assert!(check_collect(false, false, false).is_empty());
+/// ```
+pub fn check_collect(
+    has_from_iter: bool,
+    has_extend: bool,
+    is_collection: bool,
+) -> Vec<&'static str> {
+    if !is_collection {
+        return Vec::new();
+    }
+    [(has_from_iter, "FromIterator"), (has_extend, "Extend")]
+        .into_iter()
+        .filter(|&(implemented, _)| !implemented)
+        .map(|(_, name)| name)
+        .collect()
+}
+
+/// The traits [C-COMMON-TRAITS](crate::Interoperability::C_COMMON_TRAITS) asks new types to
+/// implement eagerly, each listed after its supertraits.
+const COMMON_TRAITS: &[&str] = &[
+    "Clone",
+    "Copy",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "Debug",
+    "Display",
+    "Default",
+];
+
+/// Returns the common traits a newly defined type does not implement yet, per
+/// [C-COMMON-TRAITS](crate::Interoperability::C_COMMON_TRAITS).
+///
+/// Because of the orphan rule, downstream crates can't add these impls themselves. Supertraits
+/// implied by an implemented trait count as implemented, e.g. `Copy` implies `Clone`. Trait paths
+/// such as `std::fmt::Debug` are accepted.
+///
+/// ```rust
+/// use api_guidelines::interoperability::missing_common_traits;
+///
+/// assert_eq!(
+///     missing_common_traits(&["Debug"]),
+///     ["Clone", "Copy", "PartialEq", "Eq", "PartialOrd", "Ord", "Hash", "Display", "Default"],
+/// );
+/// assert_eq!(
+///     missing_common_traits(&["Copy", "Ord", "Hash", "std::fmt::Debug", "Display"]),
+///     ["Default"],
+/// );
+/// ```
+pub fn missing_common_traits(implemented: &[&str]) -> Vec<&'static str> {
+    let is_implemented = |name: &str| {
+        implemented
+            .iter()
+            .any(|t| t.rsplit("::").next().unwrap_or(t).trim() == name)
+    };
+    let is_implied = |name: &str| match name {
+        "Clone" => is_implemented("Copy"),
+        "PartialEq" => {
+            is_implemented("Eq") || is_implemented("PartialOrd") || is_implemented("Ord")
+        }
+        "Eq" | "PartialOrd" => is_implemented("Ord"),
+        _ => false,
+    };
+    COMMON_TRAITS
+        .iter()
+        .copied()
+        .filter(|name| !is_implemented(name) && !is_implied(name))
+        .collect()
+}
+
+/// A reader or writer parameter taken by `&mut`, see [`check_rw_value`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RwValueLint {
+    /// The by-value parameter to use instead, e.g. `R: Read` or `impl Write`.
+    pub suggestion: String,
+    /// Sentence to add to the function's documentation.
+    pub reminder: String,
+}
+
+impl From<RwValueLint> for Violation {
+    fn from(lint: RwValueLint) -> Violation {
+        Violation {
+            guideline: Interoperability::C_RW_VALUE.into(),
+            message: format!(
+                "take `{}` by value and document it: {}",
+                lint.suggestion, lint.reminder
+            ),
+            suggestion: Some(lint.suggestion),
+            span_hint: None,
+        }
+    }
+}
+
+/// Flags a generic reader or writer parameter taken as `&mut R` or `&mut impl Write` instead of by
+/// value, per [C-RW-VALUE](crate::Interoperability::C_RW_VALUE).
+///
+/// Because of the blanket `impl Read for &mut R` and `impl Write for &mut W`, taking the generic by
+/// value still lets callers pass `&mut f`; the lint includes the documentation reminder saying so.
+/// A parameter is recognized as a generic reader or writer when it is `impl Read`,
+/// `impl BufRead` or `impl Write`, or a type parameter whose `bounds` include one of these traits.
+/// Trait objects such as `&mut dyn Write` are not flagged, since `dyn Write` is unsized and can't
+/// be taken by value.
+///
+/// ```rust
+/// use api_guidelines::interoperability::check_rw_value;
+///
+/// assert_eq!(check_rw_value("R", &["Read"]), None);
+/// assert_eq!(check_rw_value("&mut [u8]", &[]), None);
+/// assert_eq!(check_rw_value("&mut dyn Write", &[]), None);
+/// assert_eq!(check_rw_value("&mut T", &[]), None);
+///
+/// let lint = check_rw_value("&mut R", &["Read"]).unwrap();
+/// assert_eq!(lint.suggestion, "R: Read");
+/// assert!(lint.reminder.contains("&mut f"));
+///
+/// let lint = check_rw_value("&mut W", &["std::io::Write", "Send"]).unwrap();
+/// assert_eq!(lint.suggestion, "W: Write");
+/// assert!(lint.reminder.contains("writer"));
+///
+/// let lint = check_rw_value("&mut impl Write", &[]).unwrap();
+/// assert_eq!(lint.suggestion, "impl Write");
+/// ```
+pub fn check_rw_value(param_type: &str, bounds: &[&str]) -> Option<RwValueLint> {
+    const IO_TRAITS: &[&str] = &["Read", "BufRead", "Write"];
+
+    let pointee = param_type.trim().strip_prefix("&mut ")?.trim();
+    let (trait_name, suggestion) = if let Some(name) = pointee.strip_prefix("impl ") {
+        let name = name.rsplit("::").next().unwrap_or(name).trim();
+        (
+            IO_TRAITS.contains(&name).then_some(name)?,
+            pointee.to_owned(),
+        )
+    } else if pointee.starts_with("dyn ") {
+        return None;
+    } else {
+        let name = bounds
+            .iter()
+            .map(|bound| bound.rsplit("::").next().unwrap_or(bound).trim())
+            .find(|bound| IO_TRAITS.contains(bound))?;
+        (name, format!("{pointee}: {name}"))
+    };
+    let role = if trait_name == "Write" {
+        "writer"
+    } else {
+        "reader"
+    };
+    Some(RwValueLint {
+        suggestion,
+        reminder: format!(
+            "The {role} is taken by value, but a mutable reference can be passed as well, \
+             e.g. `&mut f`, to keep using it afterwards."
+        ),
+    })
+}
@@ -65,8 +65,56 @@
 
 #![allow(non_camel_case_types)]
 
+pub mod debuggability;
+pub mod dependability;
+pub mod documentation;
+pub mod flexibility;
+pub mod future_proofing;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+pub mod interoperability;
+pub mod macros;
+pub mod naming;
+pub mod necessities;
+pub mod predictability;
+pub mod prelude;
+pub mod type_safety;
+
+mod catalog;
+mod error;
+mod export;
+mod guideline;
+mod lint;
+mod query;
+mod report;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod set;
+mod snippet;
+mod violation;
+
+pub use catalog::Catalog;
+pub use error::{
+    CategoryMismatchError, ParseCategoryError, ParseGuidelineError, UnknownGuidelineError,
+};
+pub use export::{
+    csv_display, escape_html, markdown_checklist, markdown_checklist_display, parse_checklist,
+    parse_checklist_items, render_checklist, to_csv, to_html, write_checklist,
+};
+#[cfg(feature = "phf")]
+pub use guideline::GUIDELINES;
+pub use guideline::{Category, Guideline, GuidelineKind, Severity, validate_catalog_integrity};
+pub use lint::{ItemDescriptor, exit_code, lint_item, worst_severity};
+pub use query::CatalogQuery;
+pub use report::{Format, ReportBuilder};
+#[cfg(feature = "serde")]
+pub use serde_impls::{Detailed, write_jsonl};
+pub use set::{GuidelineSet, Iter, SetDiff, diff_sets, missing_from_baseline};
+pub use snippet::Snippet;
+pub use violation::Violation;
+
 /// Naming conventions and guidelines for Rust APIs
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Naming {
     /// In general, Rust tends to use UpperCamelCase for "type-level" constructs (types and traits) and snake_case for "value-level" constructs.
     ///
@@ -283,7 +331,7 @@ pub enum Naming {
     /// [Names use a consistent word order (C-WORD-ORDER)](https://rust-lang.github.io/api-guidelines/naming.html#names-use-a-consistent-word-order-c-word-order)
     C_WORD_ORDER,
 }
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Interoperability {
     /// Rust's trait system does not allow orphans: roughly, every impl must live either in the crate that defines the trait or the implementing type. Consequently, crates that define new types should eagerly implement all applicable, common traits.
     ///
@@ -411,6 +459,7 @@ pub enum Interoperability {
     /// [Generic reader/writer functions take R: Read and W: Write by value (C-RW-VALUE)](https://rust-lang.github.io/api-guidelines/interoperability.html#generic-readerwriter-functions-take-r-read-and-w-write-by-value-c-rw-value)
     C_RW_VALUE,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Predictability {
     /// For example, this is why the [Box::into_raw](https://doc.rust-lang.org/std/boxed/struct.Box.html#method.into_raw) function is defined the way it is.
     ///
@@ -519,6 +568,7 @@ pub enum Predictability {
     /// [Constructors are static, inherent methods (C-CTOR)](https://rust-lang.github.io/api-guidelines/predictability.html#constructors-are-static-inherent-methods-c-ctor)
     C_CTOR,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Flexibility {
     /// Many functions that answer a question also compute interesting related data. If this data is potentially of interest to the client, consider exposing it in the API.
     ///
@@ -632,6 +682,7 @@ pub enum Flexibility {
     /// [Traits are object-safe if they may be useful as a trait object (C-OBJECT)](https://rust-lang.github.io/api-guidelines/flexibility.html#traits-are-object-safe-if-they-may-be-useful-as-a-trait-object-c-object)
     C_OBJECT,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TypeSafety {
     /// Newtypes can statically distinguish between different interpretations of an underlying type.
     /// For example, a f64 value might be used to represent a quantity in miles or in kilometers. Using newtypes, we can keep track of the intended interpretation:
@@ -778,6 +829,7 @@ pub enum TypeSafety {
     /// [Builders enable construction of complex values (C-BUILDER)](https://rust-lang.github.io/api-guidelines/type-safety.html#builders-enable-construction-of-complex-values-c-builder)
     C_BUILDER,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Dependability {
     /// Rust APIs do not generally follow the [robustness principle](http://en.wikipedia.org/wiki/Robustness_principle): "be conservative in what you send; be liberal in what you accept".
     ///
@@ -832,6 +884,7 @@ pub enum Dependability {
     /// [Destructors that may block have alternatives (C-DTOR-BLOCK)](https://rust-lang.github.io/api-guidelines/dependability.html#destructors-that-may-block-have-alternatives-c-dtor-block)
     C_DTOR_BLOCK,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Debuggability {
     /// If there are exceptions, they are rare.
     ///
@@ -848,6 +901,7 @@ pub enum Debuggability {
     /// [Debug representation is never empty (C-DEBUG-NONEMPTY)](https://rust-lang.github.io/api-guidelines/debuggability.html#debug-representation-is-never-empty-c-debug-nonempty)
     C_DEBUG_NONEMPTY,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum FutureProofing {
     /// Some traits are only meant to be implemented within the crate that defines them. In such cases, we can retain the ability to make changes to the trait in a non-breaking way by using the sealed trait pattern.
     /// ```
@@ -970,6 +1024,7 @@ pub enum FutureProofing {
     /// [Data structures do not duplicate derived trait bounds (C-STRUCT-BOUNDS)]()
     C_STRUCT_BOUNDS,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Necessities {
     /// A crate cannot be stable (>=1.0.0) without all of its public dependencies being stable.
     ///
@@ -1006,6 +1061,7 @@ pub enum Necessities {
     /// [Crate and its dependencies have a permissive license (C-PERMISSIVE)](https://rust-lang.github.io/api-guidelines/necessities.html#crate-and-its-dependencies-have-a-permissive-license-c-permissive)
     C_PERMISSIVE,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Documentation {
     /// See [RFC 1687](https://github.com/rust-lang/rfcs/pull/1687).
     ///
@@ -1184,6 +1240,7 @@ pub enum Documentation {
     C_HIDDEN,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Macro {
     /// Rust macros let you dream up practically whatever input syntax you want. Aim to keep input syntax familiar and cohesive with the rest of your users' code by mirroring existing Rust syntax where possible. Pay attention to the choice and placement of keywords and punctuation.
     ///
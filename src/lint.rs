@@ -0,0 +1,194 @@
+use crate::documentation::{
+    check_example_error_handling, check_failure_sections, has_example, rust_examples,
+};
+use crate::naming::{ItemKind, lint_getter, lint_identifier};
+use crate::{Debuggability, Documentation, Severity, Violation};
+
+/// What a linter knows about an item, see [`lint_item`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemDescriptor<'a> {
+    pub kind: ItemKind,
+    pub name: &'a str,
+    /// The item is `pub`. Documentation and trait checks only apply to public items.
+    pub is_public: bool,
+    /// The function or method returns a `Result`.
+    pub returns_result: bool,
+    /// The function or method can panic.
+    pub can_panic: bool,
+    /// The function or method is `unsafe`.
+    pub is_unsafe: bool,
+    /// The doc comment, without the `///` markers.
+    pub doc: &'a str,
+    /// Traits implemented for the item, for structs and enums. Paths are accepted.
+    pub trait_impls: &'a [&'a str],
+}
+
+/// Runs every checker that applies to `item` and returns the violations in official guideline
+/// order.
+///
+/// | Guideline | Applies to |
+/// |-----------|------------|
+/// | [C-CASE](crate::Naming::C_CASE) | every item |
+/// | [C-GETTER](crate::Naming::C_GETTER) | methods |
+/// | [C-DEBUG](crate::Debuggability::C_DEBUG) | public structs and enums |
+/// | [C-EXAMPLE](crate::Documentation::C_EXAMPLE) | public items other than fields |
+/// | [C-QUESTION-MARK](crate::Documentation::C_QUESTION_MARK) | examples in public items' docs |
+/// | [C-FAILURE](crate::Documentation::C_FAILURE) | public functions and methods |
+///
+/// Findings of the individual checkers are converted into [`Violation`]s with `From`.
+///
+/// ```rust
+/// use api_guidelines::{lint_item, Documentation, Guideline, ItemDescriptor, Naming};
+/// use api_guidelines::naming::ItemKind;
+///
+/// let violations = lint_item(&ItemDescriptor {
+///     kind: ItemKind::Fn,
+///     name: "parseURL",
+///     is_public: true,
+///     returns_result: true,
+///     can_panic: false,
+///     is_unsafe: false,
+///     doc: "",
+///     trait_impls: &[],
+/// });
+/// let guidelines: Vec<Guideline> = violations.iter().map(|v| v.guideline).collect();
+/// assert_eq!(
+///     guidelines,
+///     [Naming::C_CASE.into(), Documentation::C_EXAMPLE.into(), Documentation::C_FAILURE.into()],
+/// );
+/// assert_eq!(violations[0].to_string(), "[C-CASE] parseURL: prefer parse_url");
+///
+/// let violations = lint_item(&ItemDescriptor {
+///     kind: ItemKind::Fn,
+///     name: "parse_url",
+///     is_public: true,
+///     returns_result: false,
+///     can_panic: false,
+///     is_unsafe: false,
+///     doc: "Parses a [`Url`].\n\n```\nlet url = parse_url(\"https://example.com\").unwrap();\n```",
+///     trait_impls: &[],
+/// });
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(violations[0].guideline, Documentation::C_QUESTION_MARK.into());
+/// assert_eq!(violations[0].span_hint.as_deref(), Some("line 1 of the example"));
+/// ```
+pub fn lint_item(item: &ItemDescriptor<'_>) -> Vec<Violation> {
+    let mut violations: Vec<Violation> =
+        lint_identifier(item.name, item.kind).into_iter().collect();
+    let span_hint = || Some(item.name.to_owned());
+
+    if item.kind == ItemKind::Method {
+        violations.extend(lint_getter(item.name));
+    }
+
+    if item.is_public {
+        let is_type = matches!(item.kind, ItemKind::Struct | ItemKind::Enum);
+        let implements_debug = item
+            .trait_impls
+            .iter()
+            .any(|t| t.rsplit("::").next().unwrap_or(t).trim() == "Debug");
+        if is_type && !implements_debug {
+            violations.push(Violation {
+                guideline: Debuggability::C_DEBUG.into(),
+                message: format!("{}: implement Debug", item.name),
+                suggestion: Some("#[derive(Debug)]".to_owned()),
+                span_hint: span_hint(),
+            });
+        }
+
+        if !matches!(item.kind, ItemKind::Field | ItemKind::Variable) && !has_example(item.doc) {
+            violations.push(Violation {
+                guideline: Documentation::C_EXAMPLE.into(),
+                message: format!(
+                    "{}: add an example showing how and why to use it",
+                    item.name
+                ),
+                suggestion: None,
+                span_hint: span_hint(),
+            });
+        }
+
+        for example in rust_examples(item.doc) {
+            violations.extend(
+                check_example_error_handling(&example)
+                    .into_iter()
+                    .map(Violation::from),
+            );
+        }
+
+        if matches!(item.kind, ItemKind::Fn | ItemKind::Method) {
+            for heading in check_failure_sections(
+                item.returns_result,
+                item.can_panic,
+                item.is_unsafe,
+                item.doc,
+            ) {
+                violations.push(Violation {
+                    guideline: Documentation::C_FAILURE.into(),
+                    message: format!("{}: document failures in a `{heading}` section", item.name),
+                    suggestion: None,
+                    span_hint: span_hint(),
+                });
+            }
+        }
+    }
+
+    violations.sort_by_key(|violation| violation.guideline.as_index());
+    violations
+}
+
+/// The [`severity`](crate::Guideline::severity) of the most serious violation, or `None` when there
+/// are none.
+///
+/// ```rust
+/// use api_guidelines::{worst_severity, Documentation, Severity, Violation};
+///
+/// let violation = |guideline: Documentation| Violation {
+///     guideline: guideline.into(),
+///     message: String::new(),
+///     suggestion: None,
+///     span_hint: None,
+/// };
+/// assert_eq!(worst_severity(&[]), None);
+/// assert_eq!(worst_severity(&[violation(Documentation::C_LINK)]), Some(Severity::Note));
+/// assert_eq!(
+///     worst_severity(&[violation(Documentation::C_LINK), violation(Documentation::C_FAILURE)]),
+///     Some(Severity::Warning),
+/// );
+/// ```
+pub fn worst_severity(violations: &[Violation]) -> Option<Severity> {
+    violations
+        .iter()
+        .map(|violation| violation.guideline.severity())
+        .max()
+}
+
+/// Process exit code for a CI check: `1` when any violation is at least as severe as `fail_on`,
+/// `0` otherwise.
+///
+/// ```rust
+/// use api_guidelines::{exit_code, Dependability, Documentation, Severity, Violation};
+///
+/// let note = Violation {
+///     guideline: Documentation::C_LINK.into(),
+///     message: "`Vec` is not linked".to_owned(),
+///     suggestion: None,
+///     span_hint: None,
+/// };
+/// let error = Violation {
+///     guideline: Dependability::C_DTOR_FAIL.into(),
+///     message: "`drop` can fail".to_owned(),
+///     suggestion: None,
+///     span_hint: None,
+/// };
+/// assert_eq!(exit_code(&[note.clone(), note.clone()], Severity::Error), 0);
+/// assert_ne!(exit_code(&[error], Severity::Error), 0);
+/// assert_ne!(exit_code(&[note], Severity::Note), 0);
+/// assert_eq!(exit_code(&[], Severity::Note), 0);
+/// ```
+pub fn exit_code(violations: &[Violation], fail_on: Severity) -> i32 {
+    match worst_severity(violations) {
+        Some(worst) if worst >= fail_on => 1,
+        _ => 0,
+    }
+}
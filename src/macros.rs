@@ -0,0 +1,280 @@
+//! Helpers for applying the [`Macro`] guidelines.
+
+pub use crate::Macro;
+
+/// Checks that a macro input opens with the Rust keyword for the item it declares, per
+/// [C-EVOCATIVE](crate::Macro::C_EVOCATIVE).
+///
+/// Leading attributes such as `#[derive(Default)]` and a visibility such as `pub` or `pub(crate)`,
+/// which [C-MACRO-VIS](crate::Macro::C_MACRO_VIS) asks macros to accept, are skipped. Returns a
+/// suggestion when the keyword is missing or replaced by an ad-hoc word.
+///
+/// ```rust
+/// use api_guidelines::macros::check_keyword_placement;
+///
+/// // Prefer this...
+/// assert_eq!(check_keyword_placement("struct", "struct S: u32 { /* ... */ }"), None);
+/// assert_eq!(check_keyword_placement("struct", "#[derive(Default)]\nstruct S: u32 {}"), None);
+/// assert_eq!(check_keyword_placement("struct", "pub struct S: u32 {}"), None);
+/// assert_eq!(check_keyword_placement("struct", "pub(crate) struct S: u32 {}"), None);
+///
+/// // ...over no keyword...
+/// assert!(check_keyword_placement("struct", "S: u32 { /* ... */ }").unwrap().contains("add `struct`"));
+///
+/// // ...or some ad-hoc word.
+/// assert!(check_keyword_placement("struct", "flags S: u32 { /* ... */ }").unwrap().contains("`flags`"));
+/// ```
+pub fn check_keyword_placement(expected_keyword: &str, input: &str) -> Option<String> {
+    let rest = skip_visibility(skip_attributes(input)?);
+    let first = rest
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()?;
+    let second = rest[first.len()..].trim_start();
+    if first == expected_keyword {
+        return None;
+    }
+    if second.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        Some(format!(
+            "replace `{first}` with `{expected_keyword}` so the input reads like the output (C-EVOCATIVE)"
+        ))
+    } else {
+        Some(format!(
+            "add `{expected_keyword}` before `{first}` so the input reads like the output (C-EVOCATIVE)"
+        ))
+    }
+}
+
+/// Strips leading whitespace and outer attributes, or returns `None` for an unclosed attribute.
+fn skip_attributes(input: &str) -> Option<&str> {
+    let mut rest = input.trim_start();
+    while rest.starts_with("#[") {
+        let end = rest.find(']')?;
+        rest = rest[end + 1..].trim_start();
+    }
+    Some(rest)
+}
+
+/// Strips a leading `pub` or `pub(...)` visibility and the whitespace after it.
+fn skip_visibility(input: &str) -> &str {
+    let Some(after) = input.strip_prefix("pub") else {
+        return input;
+    };
+    if after.starts_with(char::is_whitespace) {
+        after.trim_start()
+    } else if after.starts_with('(')
+        && let Some(end) = after.find(')')
+    {
+        after[end + 1..].trim_start()
+    } else {
+        input
+    }
+}
+
+/// Warns about constant-like items in a macro input that end with a comma instead of a semicolon,
+/// per [C-EVOCATIVE](crate::Macro::C_EVOCATIVE), returning one warning per item.
+///
+/// ```rust
+/// use api_guidelines::macros::check_separators;
+///
+/// // Prefer this...
+/// let input = "struct S: u32 {\n    const C = 0b000100;\n    const D = 0b001000;\n}";
+/// assert!(check_separators(input).is_empty());
+///
+/// // ...over this.
+/// let input = "struct S: u32 {\n    const E = 0b010000,\n    const F = 0b100000,\n}";
+/// let warnings = check_separators(input);
+/// assert_eq!(warnings.len(), 2);
+/// assert!(warnings[0].starts_with("`const E`"));
+/// ```
+pub fn check_separators(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("const ") && line.ends_with(','))
+        .map(|line| {
+            let name = line.split(['=', ':']).next().unwrap_or(line).trim();
+            format!(
+                "`{name}` ends with a comma; constants are followed by semicolons (C-EVOCATIVE)"
+            )
+        })
+        .collect()
+}
+
+/// Returns whether a macro expansion carries outer attributes such as `#[cfg(..)]` or
+/// `#[derive(..)]` on its items, as [C-MACRO-ATTR](crate::Macro::C_MACRO_ATTR) expects when
+/// attributes are given in the input.
+///
+/// ```rust
+/// use api_guidelines::macros::supports_item_attributes;
+///
+/// assert!(supports_item_attributes("#[derive(Default)]\nstruct Flags { bits: u8 }"));
+/// assert!(!supports_item_attributes("struct Flags { bits: u8 }"));
+/// ```
+pub fn supports_item_attributes(expansion: &str) -> bool {
+    expansion
+        .lines()
+        .any(|line| line.trim_start().starts_with("#[") && line.contains(']'))
+}
+
+/// Checks that an attribute placed on a macro input, such as `#[cfg(windows)]`, is forwarded to
+/// the expansion, per [C-MACRO-ATTR](crate::Macro::C_MACRO_ATTR).
+///
+/// Whitespace is ignored when comparing. Meant for a macro's own test suite: expand the macro with
+/// the attribute on its input, then assert that this returns `None`.
+///
+/// ```rust
+/// use api_guidelines::macros::check_derive_passthrough;
+///
+/// let forwarded = "#[derive(Default, Serialize)]\nstruct Flags { bits: u8 }";
+/// assert_eq!(check_derive_passthrough("#[derive(Default, Serialize)]", forwarded), None);
+///
+/// let dropped = "struct Flags { bits: u8 }";
+/// assert!(check_derive_passthrough("#[derive(Default, Serialize)]", dropped).is_some());
+/// ```
+pub fn check_derive_passthrough(input_attribute: &str, expansion: &str) -> Option<String> {
+    let squash = |s: &str| s.split_whitespace().collect::<String>();
+    if squash(expansion).contains(&squash(input_attribute)) {
+        None
+    } else {
+        Some(format!(
+            "`{}` on the macro input is not forwarded to the generated item (C-MACRO-ATTR)",
+            input_attribute.trim()
+        ))
+    }
+}
+
+/// Generates a test module checking that an item macro works at module scope and inside a
+/// function, per [C-ANYWHERE](crate::Macro::C_ANYWHERE).
+///
+/// `sample_invocation` is the macro input, without the macro name and delimiters. Paste the output
+/// into the crate defining `macro_name`, after the macro; the module compiles exactly when the
+/// macro works in both places.
+///
+/// ```rust
+/// use api_guidelines::macros::anywhere_test_template;
+///
+/// let code = anywhere_test_template("bitflags", "struct Flags: u8 { const A = 1; }");
+/// assert!(code.starts_with("#[cfg(test)]\n"));
+///
+/// let file = syn::parse_file(&code).unwrap();
+/// let syn::Item::Mod(module) = &file.items[0] else { panic!("expected a module") };
+/// let items = &module.content.as_ref().unwrap().1;
+///
+/// // Invoked once at module scope...
+/// assert!(items.iter().any(|item| matches!(item, syn::Item::Macro(m) if m.mac.path.is_ident("bitflags"))));
+/// // ...and once inside a test function.
+/// assert!(items.iter().any(|item| matches!(item, syn::Item::Fn(f) if f.block.stmts.len() == 1)));
+/// ```
+pub fn anywhere_test_template(macro_name: &str, sample_invocation: &str) -> String {
+    let invocation = sample_invocation.trim();
+    format!(
+        "#[cfg(test)]\n\
+         mod c_anywhere {{\n    \
+             #[allow(unused_imports)]\n    \
+             use super::*;\n\
+         \n    \
+             // Module scope.\n    \
+             {macro_name}! {{ {invocation} }}\n\
+         \n    \
+             #[test]\n    \
+             fn function_scope() {{\n        \
+                 {macro_name}! {{ {invocation} }}\n    \
+             }}\n\
+         }}\n"
+    )
+}
+
+/// Generates a test module invoking a macro that takes a `$t:ty` with each kind of type fragment
+/// listed by [C-MACRO-TY](crate::Macro::C_MACRO_TY): primitives, relative paths, absolute paths,
+/// upward relative paths and generics.
+///
+/// Each invocation sits in its own module so that the items it generates can't collide. Paste the
+/// output into the crate defining `macro_name`, after the macro; a macro that mishandles relative
+/// paths fails to compile in `relative_path` or `upward_path`.
+///
+/// ```rust
+/// use api_guidelines::macros::ty_fragment_test_template;
+///
+/// let code = ty_fragment_test_template("impl_my_trait");
+/// for fragment in [
+///     "impl_my_trait!(u8);",
+///     "impl_my_trait!(&'static str);",
+///     "impl_my_trait!(m::Data);",
+///     "impl_my_trait!(::std::string::String);",
+///     "impl_my_trait!(super::Data);",
+///     "impl_my_trait!(Vec<String>);",
+/// ] {
+///     assert!(code.contains(fragment), "missing {fragment}");
+/// }
+/// assert!(syn::parse_file(&code).is_ok());
+/// ```
+pub fn ty_fragment_test_template(macro_name: &str) -> String {
+    let cases = [
+        ("primitive", "u8"),
+        ("primitive_ref", "&'static str"),
+        ("relative_path", "m::Data"),
+        ("absolute_path", "::std::string::String"),
+        ("upward_path", "super::Data"),
+        ("generic", "Vec<String>"),
+    ];
+    let mut code = String::from(
+        "#[cfg(test)]\n\
+         #[allow(dead_code, unused_imports)]\n\
+         mod c_macro_ty {\n    \
+             use super::*;\n\
+         \n    \
+             pub struct Data;\n\
+         \n    \
+             pub mod m {\n        \
+                 pub struct Data;\n    \
+             }\n",
+    );
+    for (module, ty) in cases {
+        code.push_str(&format!(
+            "\n    mod {module} {{\n        use super::*;\n\n        {macro_name}!({ty});\n    }}\n"
+        ));
+    }
+    code.push_str("}\n");
+    code
+}
+
+/// Checks that a macro gives its generated item the visibility written in its input, per
+/// [C-MACRO-VIS](crate::Macro::C_MACRO_VIS): private by default, public when the input starts
+/// with `pub`.
+///
+/// Takes the expansions of the same input without and with a leading `pub`, and compares the
+/// visibility of the first item in each, after its attributes.
+///
+/// ```rust
+/// use api_guidelines::macros::check_visibility_support;
+///
+/// // Follows the input.
+/// assert_eq!(check_visibility_support("struct Flags(u8);", "pub struct Flags(u8);"), None);
+///
+/// // Always public, whatever the input says.
+/// let message = check_visibility_support("pub struct Flags(u8);", "pub struct Flags(u8);").unwrap();
+/// assert!(message.contains("private by default"));
+///
+/// // Drops the `pub`.
+/// let message = check_visibility_support("struct Flags(u8);", "struct Flags(u8);").unwrap();
+/// assert!(message.contains("ignores `pub`"));
+/// ```
+pub fn check_visibility_support(
+    without_pub_expansion: &str,
+    with_pub_expansion: &str,
+) -> Option<String> {
+    let is_public = |expansion: &str| {
+        skip_attributes(expansion).is_some_and(|rest| skip_visibility(rest).len() < rest.len())
+    };
+    match (is_public(without_pub_expansion), is_public(with_pub_expansion)) {
+        (false, true) => None,
+        (true, _) => Some(
+            "the generated item is public without `pub` in the input; items should be private by default (C-MACRO-VIS)"
+                .to_owned(),
+        ),
+        (false, false) => Some(
+            "the macro ignores `pub` in the input; forward it to the generated item (C-MACRO-VIS)"
+                .to_owned(),
+        ),
+    }
+}
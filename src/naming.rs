@@ -0,0 +1,491 @@
+//! Helpers for applying the [`Naming`] guidelines.
+//!
+//! Like the other per-category modules, this one re-exports its category enum next to its
+//! checkers, so a single `use` brings both into scope.
+//!
+//! ```rust
+//! use api_guidelines::{naming, type_safety};
+//!
+//! assert_eq!(naming::Naming::C_CASE.min_rust_version(), None);
+//! assert_eq!(naming::check_casing("UUID", naming::ItemKind::Struct).as_deref(), Some("Uuid"));
+//! assert!(type_safety::should_use_bitflags(true, 3).is_some());
+//! ```
+
+pub use crate::Naming;
+
+use crate::Violation;
+
+/// The kind of item an identifier names, shared by the naming checkers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    Struct,
+    Enum,
+    Trait,
+    TypeAlias,
+    /// A free function.
+    Fn,
+    /// A function in an `impl` or trait block.
+    Method,
+    Const,
+    Static,
+    /// A local `let` binding or function parameter.
+    Variable,
+    Module,
+    /// A named struct field.
+    Field,
+    /// A `macro_rules!` macro.
+    MacroRules,
+}
+
+impl ItemKind {
+    /// Parses the keyword introducing an item, as printed by `syn` or written in source, e.g.
+    /// `"struct"`, `"type"` or `"macro_rules!"`.
+    ///
+    /// Methods, fields and variables have no keyword of their own, so `"method"`, `"field"` and
+    /// `"let"` are accepted for them. Returns `None` for anything else.
+    ///
+    /// ```rust
+    /// use api_guidelines::naming::ItemKind;
+    ///
+    /// assert_eq!(ItemKind::from_syn_str("struct"), Some(ItemKind::Struct));
+    /// assert_eq!(ItemKind::from_syn_str("type"), Some(ItemKind::TypeAlias));
+    /// assert_eq!(ItemKind::from_syn_str("mod"), Some(ItemKind::Module));
+    /// assert_eq!(ItemKind::from_syn_str(" macro_rules! "), Some(ItemKind::MacroRules));
+    /// assert_eq!(ItemKind::from_syn_str("method"), Some(ItemKind::Method));
+    /// assert_eq!(ItemKind::from_syn_str("impl"), None);
+    /// ```
+    pub fn from_syn_str(s: &str) -> Option<ItemKind> {
+        let kind = match s.trim() {
+            "struct" => ItemKind::Struct,
+            "enum" => ItemKind::Enum,
+            "trait" => ItemKind::Trait,
+            "type" => ItemKind::TypeAlias,
+            "fn" => ItemKind::Fn,
+            "method" => ItemKind::Method,
+            "const" => ItemKind::Const,
+            "static" => ItemKind::Static,
+            "let" => ItemKind::Variable,
+            "mod" => ItemKind::Module,
+            "field" => ItemKind::Field,
+            "macro_rules" | "macro_rules!" => ItemKind::MacroRules,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+/// Whether an identifier lives at the type level or the value level, which decides its casing per
+/// [C-CASE](crate::Naming::C_CASE).
+///
+/// Type-level constructs are `UpperCamelCase`. Value-level constructs are `snake_case`, except
+/// constants and statics which are `SCREAMING_SNAKE_CASE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentLevel {
+    Type,
+    Value,
+}
+
+/// Returns the level at which an item of the given kind lives.
+///
+/// ```rust
+/// use api_guidelines::naming::{ident_level_for, IdentLevel, ItemKind};
+///
+/// for kind in [ItemKind::Struct, ItemKind::Enum, ItemKind::Trait, ItemKind::TypeAlias] {
+///     assert_eq!(ident_level_for(kind), IdentLevel::Type);
+/// }
+/// for kind in [
+///     ItemKind::Fn,
+///     ItemKind::Method,
+///     ItemKind::Const,
+///     ItemKind::Static,
+///     ItemKind::Variable,
+///     ItemKind::Module,
+///     ItemKind::Field,
+///     ItemKind::MacroRules,
+/// ] {
+///     assert_eq!(ident_level_for(kind), IdentLevel::Value);
+/// }
+/// ```
+pub fn ident_level_for(kind: ItemKind) -> IdentLevel {
+    match kind {
+        ItemKind::Struct | ItemKind::Enum | ItemKind::Trait | ItemKind::TypeAlias => {
+            IdentLevel::Type
+        }
+        ItemKind::Fn
+        | ItemKind::Method
+        | ItemKind::Const
+        | ItemKind::Static
+        | ItemKind::Variable
+        | ItemKind::Module
+        | ItemKind::Field
+        | ItemKind::MacroRules => IdentLevel::Value,
+    }
+}
+
+/// Checks the casing of an identifier per [C-CASE](crate::Naming::C_CASE), returning the
+/// conventional spelling when `ident` differs from it.
+///
+/// Type-level items are `UpperCamelCase`, with acronyms counting as one word (`Uuid`, not
+/// `UUID`). Constants and statics are `SCREAMING_SNAKE_CASE`, and other value-level items are
+/// `snake_case`. Leading underscores are kept.
+///
+/// ```rust
+/// use api_guidelines::naming::{check_casing, ItemKind};
+///
+/// assert_eq!(check_casing("UUID", ItemKind::Struct).as_deref(), Some("Uuid"));
+/// assert_eq!(check_casing("HTTPServer", ItemKind::Trait).as_deref(), Some("HttpServer"));
+/// assert_eq!(check_casing("parseURL", ItemKind::Fn).as_deref(), Some("parse_url"));
+/// assert_eq!(check_casing("maxSize", ItemKind::Const).as_deref(), Some("MAX_SIZE"));
+/// assert_eq!(check_casing("MyMacro", ItemKind::MacroRules).as_deref(), Some("my_macro"));
+///
+/// assert_eq!(check_casing("Uuid", ItemKind::Struct), None);
+/// assert_eq!(check_casing("_unused", ItemKind::Variable), None);
+/// ```
+pub fn check_casing(ident: &str, kind: ItemKind) -> Option<String> {
+    let body = ident.trim_start_matches('_');
+    let prefix = &ident[..ident.len() - body.len()];
+    let words = split_words(body);
+    let expected = match (ident_level_for(kind), kind) {
+        (IdentLevel::Type, _) => words
+            .iter()
+            .map(|word| {
+                let mut chars = word.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first
+                        .to_uppercase()
+                        .chain(chars.flat_map(char::to_lowercase))
+                        .collect()
+                })
+            })
+            .collect::<String>(),
+        (IdentLevel::Value, ItemKind::Const | ItemKind::Static) => words
+            .iter()
+            .map(|word| word.to_uppercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        (IdentLevel::Value, _) => words
+            .iter()
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+    };
+    let expected = format!("{prefix}{expected}");
+    (expected != ident).then_some(expected)
+}
+
+/// Runs [`check_casing`] and reports a miscased identifier as a [`Violation`] of
+/// [C-CASE](crate::Naming::C_CASE), suggesting the conventional spelling.
+///
+/// ```rust
+/// use api_guidelines::naming::{lint_identifier, ItemKind};
+///
+/// let violation = lint_identifier("parseURL", ItemKind::Fn).unwrap();
+/// assert_eq!(violation.message, "parseURL: prefer parse_url");
+/// assert_eq!(violation.span_hint.as_deref(), Some("parseURL"));
+/// assert_eq!(lint_identifier("parse_url", ItemKind::Fn), None);
+/// ```
+pub fn lint_identifier(ident: &str, kind: ItemKind) -> Option<Violation> {
+    let expected = check_casing(ident, kind)?;
+    Some(Violation {
+        guideline: Naming::C_CASE.into(),
+        message: format!("{ident}: prefer {expected}"),
+        suggestion: Some(expected),
+        span_hint: Some(ident.to_owned()),
+    })
+}
+
+/// Flags a `get_` prefix on a getter per [C-GETTER](crate::Naming::C_GETTER), suggesting the name
+/// without it.
+///
+/// The `get_mut`, `get_ref`, `get_unchecked` and `get_unchecked_mut` spellings the guideline lists
+/// as conventional are accepted, and so is a plain `get`.
+///
+/// ```rust
+/// use api_guidelines::naming::lint_getter;
+///
+/// let violation = lint_getter("get_first_name").unwrap();
+/// assert_eq!(violation.to_string(), "[C-GETTER] get_first_name: prefer first_name");
+/// assert_eq!(lint_getter("first_name"), None);
+/// assert_eq!(lint_getter("get_unchecked_mut"), None);
+/// ```
+pub fn lint_getter(method_name: &str) -> Option<Violation> {
+    let field = method_name.strip_prefix("get_")?;
+    if field.is_empty() || matches!(field, "mut" | "ref" | "unchecked" | "unchecked_mut") {
+        return None;
+    }
+    Some(Violation {
+        guideline: Naming::C_GETTER.into(),
+        message: format!("{method_name}: prefer {field}"),
+        suggestion: Some(field.to_owned()),
+        span_hint: Some(method_name.to_owned()),
+    })
+}
+
+/// Splits an identifier into words at underscores and case changes, keeping acronyms like `HTTP`
+/// in `HTTPServer` together.
+fn split_words(ident: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in ident.split('_').filter(|part| !part.is_empty()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (at, c) = chars[i];
+            let prev = chars[i - 1].1;
+            let next_is_lower = chars.get(i + 1).is_some_and(|&(_, n)| n.is_lowercase());
+            if c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_is_lower))
+            {
+                words.push(&part[start..at]);
+                start = at;
+            }
+        }
+        words.push(&part[start..]);
+    }
+    words
+}
+
+/// Runtime cost of a conversion, see [`ConversionMethod`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConversionCost {
+    Free,
+    Expensive,
+    /// Free or expensive depending on the type, like `BufWriter::into_inner` which flushes.
+    Variable,
+}
+
+/// How a conversion treats ownership of its input and output, see [`ConversionMethod`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConversionOwnership {
+    /// `&self` to a borrowed view, like `str::as_bytes`.
+    BorrowedToBorrowed,
+    /// `&self` to an owned value, like `str::to_lowercase`.
+    BorrowedToOwned,
+    /// `self` of a `Copy` type to an owned value, like `f64::to_radians`.
+    OwnedToOwnedCopy,
+    /// `self` of a non-`Copy` type, consumed, like `String::into_bytes`.
+    OwnedToOwned,
+}
+
+/// An ad-hoc conversion method to review with [`analyze_conversion`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConversionMethod<'a> {
+    /// The naming prefix, e.g. `as_`, `to_`, `into_` or `from_`.
+    pub prefix: &'a str,
+    pub cost: ConversionCost,
+    pub ownership: ConversionOwnership,
+    /// The type the method is defined on.
+    pub lives_on: &'a str,
+    /// The more specific of the two types involved, e.g. `str` rather than `[u8]`.
+    pub more_specific: &'a str,
+}
+
+/// The result of [`analyze_conversion`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ConversionAnalysis {
+    /// Set when the prefix does not match the cost and ownership, per
+    /// [C-CONV](crate::Naming::C_CONV).
+    pub prefix_lint: Option<String>,
+    /// Set when the method is not on the more specific type, per
+    /// [C-CONV-SPECIFIC](crate::Predictability::C_CONV_SPECIFIC).
+    pub placement_lint: Option<String>,
+}
+
+impl ConversionAnalysis {
+    /// Returns whether the conversion follows both guidelines.
+    pub fn is_clean(&self) -> bool {
+        self.prefix_lint.is_none() && self.placement_lint.is_none()
+    }
+}
+
+/// Reviews a conversion method against both [C-CONV](crate::Naming::C_CONV), which ties the
+/// `as_`/`to_`/`into_` prefix to cost and ownership, and
+/// [C-CONV-SPECIFIC](crate::Predictability::C_CONV_SPECIFIC), which places conversions on the
+/// more specific type.
+///
+/// `from_` constructors are exempt from the prefix check.
+///
+/// ```rust
+/// use api_guidelines::naming::{
+///     analyze_conversion, ConversionCost, ConversionMethod, ConversionOwnership,
+/// };
+///
+/// // Like `Path::to_str`, an expensive check must not be named `as_`.
+/// let analysis = analyze_conversion(&ConversionMethod {
+///     prefix: "as_",
+///     cost: ConversionCost::Expensive,
+///     ownership: ConversionOwnership::BorrowedToBorrowed,
+///     lives_on: "Path",
+///     more_specific: "Path",
+/// });
+/// assert!(analysis.prefix_lint.unwrap().contains("`to_`"));
+/// assert_eq!(analysis.placement_lint, None);
+///
+/// // `as_str` belongs on `str`'s side, not on `[u8]`.
+/// let analysis = analyze_conversion(&ConversionMethod {
+///     prefix: "as_",
+///     cost: ConversionCost::Expensive,
+///     ownership: ConversionOwnership::BorrowedToBorrowed,
+///     lives_on: "[u8]",
+///     more_specific: "str",
+/// });
+/// assert!(analysis.placement_lint.unwrap().contains("`str`"));
+///
+/// let clean = ConversionMethod {
+///     prefix: "into_",
+///     cost: ConversionCost::Free,
+///     ownership: ConversionOwnership::OwnedToOwned,
+///     lives_on: "String",
+///     more_specific: "String",
+/// };
+/// assert!(analyze_conversion(&clean).is_clean());
+/// ```
+pub fn analyze_conversion(method: &ConversionMethod) -> ConversionAnalysis {
+    let expected = match (method.ownership, method.cost) {
+        (ConversionOwnership::BorrowedToBorrowed, ConversionCost::Free) => "as_",
+        (
+            ConversionOwnership::BorrowedToBorrowed,
+            ConversionCost::Expensive | ConversionCost::Variable,
+        )
+        | (ConversionOwnership::BorrowedToOwned, _)
+        | (ConversionOwnership::OwnedToOwnedCopy, _) => "to_",
+        (ConversionOwnership::OwnedToOwned, _) => "into_",
+    };
+    let prefix = method.prefix.trim();
+    let prefix_lint = (prefix != expected && prefix != "from_").then(|| {
+        format!(
+            "use the `{expected}` prefix instead of `{prefix}` for this cost and ownership (C-CONV)"
+        )
+    });
+    let placement_lint = (method.lives_on.trim() != method.more_specific.trim()).then(|| {
+        format!(
+            "move the conversion from `{}` to the more specific `{}` (C-CONV-SPECIFIC)",
+            method.lives_on.trim(),
+            method.more_specific.trim()
+        )
+    });
+    ConversionAnalysis {
+        prefix_lint,
+        placement_lint,
+    }
+}
+
+/// A row of the [C-CONV](crate::Naming::C_CONV) table: a prefix with its cost and the ownership
+/// transitions it covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConversionRow {
+    pub prefix: &'static str,
+    pub cost: ConversionCost,
+    pub ownership_rules: &'static [ConversionOwnership],
+}
+
+/// The [C-CONV](crate::Naming::C_CONV) table of conversion prefixes, row for row.
+///
+/// ```rust
+/// use api_guidelines::naming::{ConversionCost, CONVERSION_TABLE};
+///
+/// let rows: Vec<_> = CONVERSION_TABLE.iter().map(|row| (row.prefix, row.cost)).collect();
+/// assert_eq!(
+///     rows,
+///     [
+///         ("as_", ConversionCost::Free),
+///         ("to_", ConversionCost::Expensive),
+///         ("into_", ConversionCost::Variable),
+///     ],
+/// );
+/// ```
+pub const CONVERSION_TABLE: &[ConversionRow] = &[
+    ConversionRow {
+        prefix: "as_",
+        cost: ConversionCost::Free,
+        ownership_rules: &[ConversionOwnership::BorrowedToBorrowed],
+    },
+    ConversionRow {
+        prefix: "to_",
+        cost: ConversionCost::Expensive,
+        ownership_rules: &[
+            ConversionOwnership::BorrowedToBorrowed,
+            ConversionOwnership::BorrowedToOwned,
+            ConversionOwnership::OwnedToOwnedCopy,
+        ],
+    },
+    ConversionRow {
+        prefix: "into_",
+        cost: ConversionCost::Variable,
+        ownership_rules: &[ConversionOwnership::OwnedToOwned],
+    },
+];
+
+/// Looks up the [`CONVERSION_TABLE`] row for a prefix, with or without its trailing underscore.
+///
+/// ```rust
+/// use api_guidelines::naming::{lookup_by_prefix, ConversionOwnership};
+///
+/// let row = lookup_by_prefix("into_").unwrap();
+/// assert_eq!(row.ownership_rules, [ConversionOwnership::OwnedToOwned]);
+/// assert_eq!(lookup_by_prefix("to"), lookup_by_prefix("to_"));
+/// assert_eq!(lookup_by_prefix("from_"), None);
+/// ```
+pub fn lookup_by_prefix(prefix: &str) -> Option<&'static ConversionRow> {
+    let prefix = prefix.trim();
+    let prefix = prefix.strip_suffix('_').unwrap_or(prefix);
+    CONVERSION_TABLE
+        .iter()
+        .find(|row| row.prefix.strip_suffix('_') == Some(prefix))
+}
+
+/// Checks that a type wrapping a single value gives access to it through `into_inner`, per
+/// [C-CONV](crate::Naming::C_CONV), like `BufReader::into_inner` or `AtomicBool::into_inner`.
+///
+/// `accessor_names` are the methods returning the wrapped value. Nothing is reported for types
+/// that are not single-value wrappers, that already have `into_inner` or that have no accessor.
+///
+/// ```rust
+/// use api_guidelines::naming::check_wrapper_accessor;
+///
+/// assert_eq!(check_wrapper_accessor(true, &["into_inner"]), None);
+/// assert!(check_wrapper_accessor(true, &["inner"]).unwrap().contains("rename `inner` to `into_inner`"));
+/// assert_eq!(check_wrapper_accessor(false, &["inner"]), None);
+/// ```
+pub fn check_wrapper_accessor(
+    is_single_value_wrapper: bool,
+    accessor_names: &[&str],
+) -> Option<String> {
+    if !is_single_value_wrapper
+        || accessor_names
+            .iter()
+            .any(|name| name.trim() == "into_inner")
+    {
+        return None;
+    }
+    let name = accessor_names.first()?.trim();
+    Some(format!(
+        "rename `{name}` to `into_inner`; wrappers give access to the wrapped value through `into_inner` (C-CONV)"
+    ))
+}
+
+/// Checks that `mut` in a conversion name sits where it appears in the returned type, per
+/// [C-CONV](crate::Naming::C_CONV): `as_mut_slice` returns a `&mut [T]`, so it is preferred over
+/// `as_slice_mut`. Returns the suggested name.
+///
+/// ```rust
+/// use api_guidelines::naming::check_mut_conversion_name;
+///
+/// assert!(check_mut_conversion_name("as_slice_mut").unwrap().contains("`as_mut_slice`"));
+/// assert_eq!(check_mut_conversion_name("as_mut_slice"), None);
+/// assert_eq!(check_mut_conversion_name("as_mut"), None);
+/// ```
+pub fn check_mut_conversion_name(name: &str) -> Option<String> {
+    let name = name.trim();
+    let prefix = ["as_", "to_", "into_"]
+        .into_iter()
+        .find(|prefix| name.starts_with(prefix))?;
+    let target = name[prefix.len()..].strip_suffix("_mut")?;
+    if target.is_empty() || target.starts_with("mut_") {
+        return None;
+    }
+    Some(format!(
+        "rename `{name}` to `{prefix}mut_{target}` so `mut` appears where it does in the returned type (C-CONV)"
+    ))
+}
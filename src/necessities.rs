@@ -0,0 +1,219 @@
+//! Helpers for applying the [`Necessities`] guidelines.
+
+pub use crate::Necessities;
+
+/// A dependency whose types appear in the public API, see [`check_public_deps`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DepInfo<'a> {
+    pub name: &'a str,
+    /// The version requirement, e.g. `0.4`, `^1.2.3` or `=0.9.1`.
+    pub version: &'a str,
+}
+
+/// Checks the public dependencies of a crate against [C-STABLE](crate::Necessities::C_STABLE),
+/// returning one violation per pre-1.0 dependency.
+///
+/// A crate cannot be stable while its public API exposes types from an unstable crate, so nothing
+/// is reported unless `crate_is_stable`, i.e. the crate's own version is at least 1.0.0.
+///
+/// ```rust
+/// use api_guidelines::necessities::{check_public_deps, DepInfo};
+///
+/// let deps = [
+///     DepInfo { name: "byteorder", version: "0.4" },
+///     DepInfo { name: "serde", version: "1.0" },
+/// ];
+/// let violations = check_public_deps(true, &deps);
+/// assert_eq!(violations.len(), 1);
+/// assert!(violations[0].starts_with("`byteorder`"));
+///
+/// assert!(check_public_deps(false, &deps).is_empty());
+///
+/// let ranges = [
+///     DepInfo { name: "rand", version: ">=0.3, <0.5" },
+///     DepInfo { name: "nom", version: "=0.2.1" },
+///     DepInfo { name: "regex", version: ">= 1.2, < 2" },
+/// ];
+/// assert_eq!(check_public_deps(true, &ranges).len(), 2);
+/// ```
+pub fn check_public_deps(crate_is_stable: bool, public_deps: &[DepInfo]) -> Vec<String> {
+    if !crate_is_stable {
+        return Vec::new();
+    }
+    public_deps
+        .iter()
+        .filter(|dep| is_pre_1_0(dep.version))
+        .map(|dep| {
+            format!(
+                "`{}` {} is not stable yet but appears in the public API of a stable crate (C-STABLE)",
+                dep.name,
+                dep.version.trim()
+            )
+        })
+        .collect()
+}
+
+/// Returns whether a version requirement admits versions below 1.0.0, i.e. whether the lowest
+/// version named by any of its comma-separated comparators is a `0.x` version.
+fn is_pre_1_0(version: &str) -> bool {
+    version.split(',').any(|comparator| {
+        let version = comparator.trim_start_matches(['^', '~', '=', '>', '<', ' ']);
+        version.trim().split('.').next() == Some("0")
+    })
+}
+
+/// License identifier prefixes of copyleft licenses that a permissively licensed crate should not
+/// depend on.
+const COPYLEFT_LICENSES: &[&str] = &[
+    "GPL-",
+    "LGPL-",
+    "AGPL-",
+    "MPL-",
+    "EPL-",
+    "EUPL-",
+    "CDDL-",
+    "OSL-",
+    "CC-BY-SA-",
+];
+
+/// Checks a crate's license and its dependencies' licenses against
+/// [C-PERMISSIVE](crate::Necessities::C_PERMISSIVE), returning one warning per problem.
+///
+/// Both arguments are SPDX expressions such as `MIT OR Apache-2.0` or `GPL-3.0-only`. A dependency
+/// is flagged when every alternative of its expression includes a copyleft license, which would
+/// impose that license on users of a permissively licensed crate. Dependencies with an empty
+/// expression are skipped. A crate licensed under Apache-2.0 alone also gets a note, since that
+/// license is incompatible with GPLv2.
+///
+/// ```rust
+/// use api_guidelines::necessities::check_license;
+///
+/// let warnings = check_license("MIT", &["GPL-3.0"]);
+/// assert_eq!(warnings.len(), 1);
+/// assert!(warnings[0].contains("GPL-3.0"));
+///
+/// assert!(check_license("MIT", &["MIT"]).is_empty());
+/// assert!(check_license("MIT OR Apache-2.0", &["MIT OR GPL-2.0-only", "Unlicense"]).is_empty());
+/// assert!(check_license("Apache-2.0", &[])[0].contains("GPLv2"));
+///
+/// // A dependency without a license expression says nothing either way.
+/// assert!(check_license("MIT OR Apache-2.0", &["", "  "]).is_empty());
+///
+/// // `AND` binds tighter than `OR`, so the GPL requirement applies to both alternatives.
+/// assert_eq!(check_license("MIT", &["(MIT OR Apache-2.0) AND GPL-3.0"]).len(), 1);
+/// assert!(check_license("MIT", &["MIT OR Apache-2.0 AND GPL-3.0"]).is_empty());
+/// ```
+pub fn check_license(crate_license: &str, dep_licenses: &[&str]) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let crate_alternatives = spdx_alternatives(crate_license);
+    if crate_alternatives == [vec!["Apache-2.0"]] {
+        warnings.push(
+            "licensed under Apache-2.0 only, which is incompatible with GPLv2; prefer `MIT OR Apache-2.0` (C-PERMISSIVE)"
+                .to_owned(),
+        );
+    }
+    if !crate_alternatives
+        .iter()
+        .any(|all| !all.iter().any(|l| is_copyleft(l)))
+    {
+        return warnings;
+    }
+    for dep in dep_licenses {
+        let alternatives = spdx_alternatives(dep);
+        if !alternatives.is_empty()
+            && alternatives
+                .iter()
+                .all(|all| all.iter().any(|l| is_copyleft(l)))
+        {
+            warnings.push(format!(
+                "dependency licensed under `{}` is copyleft, which is incompatible with the permissive `{}` (C-PERMISSIVE)",
+                dep.trim(),
+                crate_license.trim()
+            ));
+        }
+    }
+    warnings
+}
+
+/// Expands an SPDX expression into its `OR` alternatives, each being the licenses joined by `AND`.
+///
+/// `AND` binds tighter than `OR` and parentheses group, so `(MIT OR Apache-2.0) AND GPL-3.0` has
+/// the alternatives `MIT AND GPL-3.0` and `Apache-2.0 AND GPL-3.0`. `WITH` exceptions are dropped
+/// and unbalanced parentheses are closed at the end of the expression.
+fn spdx_alternatives(expression: &str) -> Vec<Vec<&str>> {
+    let mut tokens = spdx_tokens(expression).into_iter().peekable();
+    let mut alternatives = spdx_or(&mut tokens);
+    alternatives.retain(|all| !all.is_empty());
+    alternatives
+}
+
+type SpdxTokens<'a> = std::iter::Peekable<std::vec::IntoIter<&'a str>>;
+
+/// Parses `and_expr ("OR" and_expr)*`.
+fn spdx_or<'a>(tokens: &mut SpdxTokens<'a>) -> Vec<Vec<&'a str>> {
+    let mut alternatives = spdx_and(tokens);
+    while tokens.next_if_eq(&"OR").is_some() {
+        alternatives.extend(spdx_and(tokens));
+    }
+    alternatives
+}
+
+/// Parses `primary ("AND" primary)*`, distributing `AND` over the alternatives of each operand.
+fn spdx_and<'a>(tokens: &mut SpdxTokens<'a>) -> Vec<Vec<&'a str>> {
+    let mut alternatives = spdx_primary(tokens);
+    while tokens.next_if_eq(&"AND").is_some() {
+        let rhs = spdx_primary(tokens);
+        alternatives = alternatives
+            .iter()
+            .flat_map(|lhs| rhs.iter().map(move |rhs| [lhs.as_slice(), rhs].concat()))
+            .collect();
+    }
+    alternatives
+}
+
+/// Parses a parenthesized expression or a license with an optional `WITH` exception.
+fn spdx_primary<'a>(tokens: &mut SpdxTokens<'a>) -> Vec<Vec<&'a str>> {
+    match tokens.next() {
+        Some("(") => {
+            let alternatives = spdx_or(tokens);
+            tokens.next_if_eq(&")");
+            alternatives
+        }
+        Some(license) if license != ")" => {
+            if tokens.next_if_eq(&"WITH").is_some() {
+                tokens.next();
+            }
+            vec![vec![license]]
+        }
+        _ => vec![Vec::new()],
+    }
+}
+
+/// Splits an SPDX expression into license, operator and parenthesis tokens.
+fn spdx_tokens(expression: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in expression.char_indices() {
+        if c.is_whitespace() || c == '(' || c == ')' {
+            if let Some(start) = start.take() {
+                tokens.push(&expression[start..i]);
+            }
+            if !c.is_whitespace() {
+                tokens.push(&expression[i..i + 1]);
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(start) = start {
+        tokens.push(&expression[start..]);
+    }
+    tokens
+}
+
+/// Returns whether an SPDX license identifier names a copyleft license.
+fn is_copyleft(license: &str) -> bool {
+    COPYLEFT_LICENSES
+        .iter()
+        .any(|prefix| license.starts_with(prefix))
+}
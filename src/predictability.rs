@@ -0,0 +1,522 @@
+//! Helpers for applying the [`Predictability`] guidelines.
+
+pub use crate::Predictability;
+
+use crate::{TypeSafety, Violation};
+
+/// A function parameter as written in the source, e.g. `output: &mut Bar`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParamInfo<'a> {
+    pub name: &'a str,
+    pub ty: &'a str,
+}
+
+/// A parameter that looks like an out-parameter, see [`has_out_parameter`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OutParamLint {
+    /// Name of the offending parameter.
+    pub param: String,
+    /// Compound return type to use instead, e.g. `(Bar, Bar)`.
+    pub suggested_return: String,
+}
+
+impl From<OutParamLint> for Violation {
+    fn from(lint: OutParamLint) -> Violation {
+        Violation {
+            guideline: Predictability::C_NO_OUT.into(),
+            message: format!(
+                "`{}` is an out-parameter; return `{}` instead",
+                lint.param, lint.suggested_return
+            ),
+            suggestion: Some(lint.suggested_return),
+            span_hint: Some(lint.param),
+        }
+    }
+}
+
+/// Detects the [C-NO-OUT](crate::Predictability::C_NO_OUT) anti-pattern of a function that returns
+/// a value while also writing a result through a `&mut T` parameter.
+///
+/// Buffers the caller already owns and wants to re-use, like `&mut [u8]`, `&mut Vec<T>` or
+/// `&mut String`, are the guideline's documented exception and are not flagged. Neither are trait
+/// objects such as `&mut dyn Write`, functions returning `()`, whose purpose is to modify the
+/// caller's data, or the `self` receiver.
+///
+/// This is a signature-only heuristic: it cannot tell whether the function also reads the
+/// parameter's previous value, in which case `&mut T` is a legitimate in-out parameter.
+///
+/// ```rust
+/// use api_guidelines::predictability::{has_out_parameter, ParamInfo};
+///
+/// // fn foo(output: &mut Bar) -> Bar
+/// let params = [ParamInfo { name: "output", ty: "&mut Bar" }];
+/// let lint = has_out_parameter(&params, "Bar").unwrap();
+/// assert_eq!(lint.param, "output");
+/// assert_eq!(lint.suggested_return, "(Bar, Bar)");
+///
+/// // fn foo<'a>(output: &'a mut Bar) -> Bar
+/// let params = [ParamInfo { name: "output", ty: "&'a mut Bar" }];
+/// assert_eq!(has_out_parameter(&params, "Bar").unwrap().suggested_return, "(Bar, Bar)");
+///
+/// // fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+/// let params = [
+///     ParamInfo { name: "self", ty: "&mut Self" },
+///     ParamInfo { name: "buf", ty: "&mut [u8]" },
+/// ];
+/// assert_eq!(has_out_parameter(&params, "io::Result<usize>"), None);
+/// ```
+pub fn has_out_parameter(params: &[ParamInfo<'_>], return_type: &str) -> Option<OutParamLint> {
+    let return_type = return_type.trim();
+    if return_type.is_empty() || return_type == "()" {
+        return None;
+    }
+    params
+        .iter()
+        .filter(|param| param.name != "self")
+        .find_map(|param| {
+            let (true, pointee) = strip_reference(param.ty)? else {
+                return None;
+            };
+            if is_reusable_buffer(pointee) {
+                return None;
+            }
+            Some(OutParamLint {
+                param: param.name.to_owned(),
+                suggested_return: format!("({return_type}, {pointee})"),
+            })
+        })
+}
+
+fn is_reusable_buffer(ty: &str) -> bool {
+    ty.starts_with('[') || ty.starts_with("Vec<") || ty == "String" || ty.starts_with("dyn ")
+}
+
+/// A `Deref`/`DerefMut` impl on a type that is not a smart pointer, see [`check_deref_impl`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DerefLint {
+    /// Name of the type implementing `Deref`.
+    pub type_name: String,
+    /// Explanation citing C-DEREF.
+    pub message: String,
+}
+
+impl From<DerefLint> for Violation {
+    fn from(lint: DerefLint) -> Violation {
+        Violation::from_message(Predictability::C_DEREF, &lint.message, Some(lint.type_name))
+    }
+}
+
+/// Warns when `Deref` or `DerefMut` is implemented for a type that is not a smart pointer, per
+/// [C-DEREF](crate::Predictability::C_DEREF).
+///
+/// Whether a type is a smart pointer, i.e. wraps a single value with pointer semantics like `Box`,
+/// `Rc` or `String`, is a judgment call left to the caller. Without that, a `Deref` impl usually
+/// only serves to inject the target's methods into the type.
+///
+/// ```rust
+/// use api_guidelines::predictability::check_deref_impl;
+///
+/// let lint = check_deref_impl(false, "Config").unwrap();
+/// assert_eq!(lint.type_name, "Config");
+/// assert!(lint.message.contains("C-DEREF"));
+///
+/// assert_eq!(check_deref_impl(true, "MyBox"), None);
+/// ```
+pub fn check_deref_impl(target_is_smart_pointer: bool, type_name: &str) -> Option<DerefLint> {
+    if target_is_smart_pointer {
+        return None;
+    }
+    Some(DerefLint {
+        type_name: type_name.to_owned(),
+        message: format!(
+            "`{type_name}` implements Deref but is not a smart pointer; \
+             expose the inner value through methods or AsRef instead (C-DEREF)"
+        ),
+    })
+}
+
+/// An inherent `self` method on a smart pointer, see [`check_smart_ptr_method`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SmartPtrLint {
+    /// Name of the offending method.
+    pub method: String,
+    /// Signature of the associated function to use instead, e.g. `fn into_raw(this: Self)`.
+    pub suggestion: String,
+}
+
+impl From<SmartPtrLint> for Violation {
+    fn from(lint: SmartPtrLint) -> Violation {
+        Violation {
+            guideline: Predictability::C_SMART_PTR.into(),
+            message: format!(
+                "`{}` takes `self` on a smart pointer; make it an associated function",
+                lint.method
+            ),
+            suggestion: Some(lint.suggestion),
+            span_hint: Some(lint.method),
+        }
+    }
+}
+
+/// Flags an inherent method on a smart pointer that takes `self` by value, per
+/// [C-SMART-PTR](crate::Predictability::C_SMART_PTR).
+///
+/// Through `Deref`, a call like `boxed_str.into_raw()` reads as a method on the pointee, so such
+/// methods should be associated functions called as `Box::into_raw(b)` instead.
+///
+/// ```rust
+/// use api_guidelines::predictability::check_smart_ptr_method;
+///
+/// // impl<T: ?Sized> Box<T> { fn into_raw(self) -> *mut T }
+/// let lint = check_smart_ptr_method("into_raw", true).unwrap();
+/// assert_eq!(lint.method, "into_raw");
+/// assert_eq!(lint.suggestion, "fn into_raw(this: Self)");
+///
+/// assert_eq!(check_smart_ptr_method("into_raw", false), None);
+/// ```
+pub fn check_smart_ptr_method(
+    method_name: &str,
+    takes_self_by_value: bool,
+) -> Option<SmartPtrLint> {
+    if !takes_self_by_value {
+        return None;
+    }
+    Some(SmartPtrLint {
+        method: method_name.to_owned(),
+        suggestion: format!("fn {method_name}(this: Self)"),
+    })
+}
+
+/// Returns whether a free function ought to be a method of `owning_type`, per
+/// [C-METHOD](crate::Predictability::C_METHOD).
+///
+/// That is the case when the function has a clear receiver and its first parameter is
+/// `owning_type` itself, taken by value or by (mutable) reference. Deciding whether the receiver is
+/// clear is the caller's responsibility; this only compares the types, ignoring references,
+/// lifetimes, generic arguments and module paths.
+///
+/// ```rust
+/// use api_guidelines::predictability::should_be_method;
+///
+/// // fn resize(widget: &mut Widget, width: u32)
+/// assert!(should_be_method(true, "&mut Widget", "Widget"));
+/// assert!(should_be_method(true, "&'a ui::Widget<T>", "Widget"));
+/// assert!(!should_be_method(true, "&Gadget", "Widget"));
+/// assert!(!should_be_method(false, "&Widget", "Widget"));
+/// ```
+pub fn should_be_method(
+    has_clear_receiver: bool,
+    first_param_type: &str,
+    owning_type: &str,
+) -> bool {
+    has_clear_receiver && base_type_name(first_param_type) == base_type_name(owning_type)
+}
+
+/// Strips references, lifetimes, generic arguments and paths from a type, so that `&'a mut m::T<U>`
+/// becomes `T`.
+fn base_type_name(ty: &str) -> &str {
+    let mut ty = ty.trim();
+    while let Some((_, pointee)) = strip_reference(ty) {
+        ty = pointee;
+    }
+    let ty = ty.split('<').next().unwrap_or(ty);
+    ty.rsplit("::").next().unwrap_or(ty).trim()
+}
+
+/// Strips one leading reference from a type, including its lifetime, returning whether the
+/// reference is `mut` and the pointee, e.g. `(true, "Bar")` for `&'a mut Bar`.
+fn strip_reference(ty: &str) -> Option<(bool, &str)> {
+    let mut ty = ty.trim().strip_prefix('&')?.trim_start();
+    if ty.starts_with('\'') {
+        ty = ty
+            .split_once(char::is_whitespace)
+            .map_or("", |(_, rest)| rest.trim_start());
+    }
+    Some(match ty.strip_prefix("mut ") {
+        Some(pointee) => (true, pointee.trim_start()),
+        None => (false, ty),
+    })
+}
+
+/// Caller-declared semantics of an operator overload, see [`check_operator_overload`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpSemantics {
+    /// The operation bears some resemblance to the operator's usual meaning, e.g. `Mul` multiplies.
+    pub resembles_operator: bool,
+    /// `(a op b) op c` equals `a op (b op c)`.
+    pub associative: bool,
+}
+
+/// A surprising operator overload, see [`check_operator_overload`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OverloadLint {
+    /// The `std::ops` trait being implemented.
+    pub trait_name: String,
+    /// Explanation citing C-OVERLOAD.
+    pub message: String,
+}
+
+impl From<OverloadLint> for Violation {
+    fn from(lint: OverloadLint) -> Violation {
+        Violation::from_message(
+            Predictability::C_OVERLOAD,
+            &lint.message,
+            Some(lint.trait_name),
+        )
+    }
+}
+
+/// Flags `std::ops` impls whose declared semantics clash with the operator, per
+/// [C-OVERLOAD](crate::Predictability::C_OVERLOAD).
+///
+/// What counts as "resembling multiplication" is subjective, so the caller declares it in
+/// [`OpSemantics`] and only clear mismatches are reported: an operation unlike the operator, or a
+/// non-associative implementation of `Add`, `Mul`, `BitAnd`, `BitOr` or `BitXor` (or their
+/// `*Assign` forms). Traits outside `std::ops` are ignored.
+///
+/// ```rust
+/// use api_guidelines::predictability::{check_operator_overload, OpSemantics};
+///
+/// let add = OpSemantics { resembles_operator: true, associative: false };
+/// let lint = check_operator_overload("Add", add).unwrap();
+/// assert!(lint.message.contains("associative"));
+///
+/// let mul = OpSemantics { resembles_operator: true, associative: true };
+/// assert_eq!(check_operator_overload("std::ops::Mul", mul), None);
+///
+/// let pipe = OpSemantics { resembles_operator: false, associative: true };
+/// assert!(check_operator_overload("BitOr", pipe).is_some());
+/// ```
+pub fn check_operator_overload(trait_name: &str, semantics: OpSemantics) -> Option<OverloadLint> {
+    let name = trait_name.rsplit("::").next().unwrap_or(trait_name).trim();
+    let op = name.strip_suffix("Assign").unwrap_or(name);
+    let (symbol, meaning, associative) = match op {
+        "Add" => ("+", "addition", true),
+        "Sub" => ("-", "subtraction", false),
+        "Mul" => ("*", "multiplication", true),
+        "Div" => ("/", "division", false),
+        "Rem" => ("%", "remainder", false),
+        "Neg" => ("-", "negation", false),
+        "Not" => ("!", "logical negation", false),
+        "BitAnd" => ("&", "bitwise and", true),
+        "BitOr" => ("|", "bitwise or", true),
+        "BitXor" => ("^", "bitwise xor", true),
+        "Shl" => ("<<", "left shift", false),
+        "Shr" => (">>", "right shift", false),
+        _ => return None,
+    };
+    let message = if !semantics.resembles_operator {
+        format!(
+            "`{name}` is implemented for an operation that bears no resemblance to {meaning}; \
+             users expect `{symbol}` to behave like it (C-OVERLOAD)"
+        )
+    } else if associative && !semantics.associative {
+        format!(
+            "`{name}` is implemented for a non-associative operation, but `{symbol}` is expected to be associative (C-OVERLOAD)"
+        )
+    } else {
+        return None;
+    };
+    Some(OverloadLint {
+        trait_name: name.to_owned(),
+        message,
+    })
+}
+
+/// A constructor naming issue, see [`check_constructor_name`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CtorLint {
+    /// A generic name like `create` is used where the primary constructor should be `new`.
+    PreferNew,
+    /// A conversion constructor should be prefixed with `from_`.
+    ConversionPrefix { suggestion: String },
+    /// A safe conversion from a single value may be better expressed as a `From` impl.
+    ConsiderFromImpl,
+    /// Secondary constructors are suffixed `_with_foo`, like `with_capacity`.
+    SecondaryWith { suggestion: String },
+    /// The constructor takes many arguments; consider a builder (C-BUILDER).
+    ConsiderBuilder,
+}
+
+/// Converts to a [C-CTOR](crate::Predictability::C_CTOR) violation, or
+/// [C-BUILDER](crate::TypeSafety::C_BUILDER) for
+/// [`ConsiderBuilder`](CtorLint::ConsiderBuilder).
+impl From<CtorLint> for Violation {
+    fn from(lint: CtorLint) -> Violation {
+        let (message, suggestion) = match lint {
+            CtorLint::PreferNew => (
+                "name the primary constructor `new`".to_owned(),
+                Some("new".to_owned()),
+            ),
+            CtorLint::ConversionPrefix { suggestion } => (
+                format!("prefix conversion constructors with `from_`, e.g. `{suggestion}`"),
+                Some(suggestion),
+            ),
+            CtorLint::ConsiderFromImpl => (
+                "consider a `From` impl for this infallible conversion".to_owned(),
+                None,
+            ),
+            CtorLint::SecondaryWith { suggestion } => (
+                format!("suffix secondary constructors with `_with_foo`, e.g. `{suggestion}`"),
+                Some(suggestion),
+            ),
+            CtorLint::ConsiderBuilder => {
+                return Violation {
+                    guideline: TypeSafety::C_BUILDER.into(),
+                    message: "the constructor takes many arguments; consider a builder".to_owned(),
+                    suggestion: None,
+                    span_hint: None,
+                };
+            }
+        };
+        Violation {
+            guideline: Predictability::C_CTOR.into(),
+            message,
+            suggestion,
+            span_hint: None,
+        }
+    }
+}
+
+/// Checks a constructor's name against the [C-CTOR](crate::Predictability::C_CTOR) conventions.
+///
+/// Domain-specific names like `File::open` or `TcpStream::connect` are accepted; only generic
+/// verbs such as `create` or `make` are asked to become `new`. Constructors taking more than three
+/// arguments are pointed to the builder pattern.
+///
+/// ```rust
+/// use api_guidelines::predictability::{check_constructor_name, CtorLint};
+///
+/// assert!(check_constructor_name("new", 0, false, false).is_empty());
+/// assert!(check_constructor_name("from_raw", 1, true, true).is_empty());
+/// assert_eq!(check_constructor_name("create", 1, false, false), [CtorLint::PreferNew]);
+/// assert_eq!(check_constructor_name("from_bytes", 1, true, false), [CtorLint::ConsiderFromImpl]);
+/// assert_eq!(
+///     check_constructor_name("new_capacity", 1, false, false),
+///     [CtorLint::SecondaryWith { suggestion: "with_capacity".to_owned() }],
+/// );
+/// ```
+pub fn check_constructor_name(
+    name: &str,
+    arg_count: usize,
+    is_conversion: bool,
+    is_unsafe: bool,
+) -> Vec<CtorLint> {
+    const GENERIC_VERBS: &[&str] = &[
+        "create",
+        "make",
+        "construct",
+        "init",
+        "initialize",
+        "instance",
+    ];
+
+    let mut lints = Vec::new();
+    if is_conversion {
+        match name.strip_prefix("from_") {
+            Some(_) if !is_unsafe && arg_count == 1 => lints.push(CtorLint::ConsiderFromImpl),
+            Some(_) => {}
+            None => {
+                let source = name
+                    .strip_prefix("new_from_")
+                    .or_else(|| name.strip_prefix("new_"))
+                    .unwrap_or(name);
+                lints.push(CtorLint::ConversionPrefix {
+                    suggestion: format!("from_{source}"),
+                });
+            }
+        }
+    } else if GENERIC_VERBS.contains(&name) {
+        lints.push(CtorLint::PreferNew);
+    } else if let Some(detail) = name.strip_prefix("new_")
+        && !detail.starts_with("with_")
+    {
+        lints.push(CtorLint::SecondaryWith {
+            suggestion: format!("with_{detail}"),
+        });
+    }
+    if arg_count > 3 {
+        lints.push(CtorLint::ConsiderBuilder);
+    }
+    lints
+}
+
+/// Reminds authors of types with both `Default` and a no-argument `new()` to keep the two
+/// consistent, per [C-CTOR](crate::Predictability::C_CTOR).
+///
+/// ```rust
+/// use api_guidelines::predictability::check_default_new_consistency;
+///
+/// assert!(check_default_new_consistency(true, true).unwrap().contains("Default"));
+/// assert_eq!(check_default_new_consistency(true, false), None);
+/// assert_eq!(check_default_new_consistency(false, true), None);
+/// ```
+pub fn check_default_new_consistency(has_default: bool, has_new_no_args: bool) -> Option<String> {
+    (has_default && has_new_no_args).then(|| {
+        "`Default` and `new()` should have the same behavior; implement one in terms of the other (C-CTOR)"
+            .to_owned()
+    })
+}
+
+/// Checks where a conversion between `type_a` and another type lives, per
+/// [C-CONV-SPECIFIC](crate::Predictability::C_CONV_SPECIFIC): it belongs on the more specific of
+/// the two, like `str::as_bytes` rather than a `[u8]` method.
+///
+/// `method_defined_on` is compared with `type_a` ignoring references, lifetimes, generic arguments
+/// and paths; any other type is taken to be the second type.
+///
+/// ```rust
+/// use api_guidelines::predictability::conversion_home;
+///
+/// // `str::as_bytes`: `str` is more specific than `[u8]` and hosts the conversion.
+/// assert_eq!(conversion_home("str", true, "&str"), None);
+/// assert_eq!(conversion_home("[u8]", false, "str"), None);
+///
+/// // A `[u8]::as_str` would be misplaced.
+/// let message = conversion_home("str", true, "[u8]").unwrap();
+/// assert!(message.contains("move the conversion from `[u8]` to `str`"));
+/// let message = conversion_home("[u8]", false, "&[u8]").unwrap();
+/// assert!(message.contains("the more specific type"));
+/// ```
+pub fn conversion_home(
+    type_a: &str,
+    a_is_more_specific: bool,
+    method_defined_on: &str,
+) -> Option<String> {
+    let on_a = base_type_name(method_defined_on) == base_type_name(type_a);
+    match (a_is_more_specific, on_a) {
+        (true, true) | (false, false) => None,
+        (true, false) => Some(format!(
+            "move the conversion from `{}` to `{}`, the more specific type (C-CONV-SPECIFIC)",
+            base_type_name(method_defined_on),
+            base_type_name(type_a)
+        )),
+        (false, true) => Some(format!(
+            "move the conversion from `{}` to the more specific type (C-CONV-SPECIFIC)",
+            base_type_name(type_a)
+        )),
+    }
+}
+
+/// Suggests offering a `to_`, `as_` or `into_` method alongside a `from_` constructor, per
+/// [C-CONV-SPECIFIC](crate::Predictability::C_CONV_SPECIFIC): when in doubt the method form is
+/// preferred, as it is more ergonomic and chains with other methods.
+///
+/// `could_be_method` tells whether the conversion could live on the source type. It can't when
+/// that type is foreign or the constructor takes several inputs, and then `from_` is justified.
+///
+/// ```rust
+/// use api_guidelines::predictability::prefer_method_over_from;
+///
+/// // `Celsius::from_fahrenheit(f)` alone: `f.to_celsius()` would chain.
+/// assert!(prefer_method_over_from(true, true).unwrap().contains("chain"));
+///
+/// // `String::from_utf8(bytes)`: `Vec<u8>` is a foreign type, so `from_` is the way.
+/// assert_eq!(prefer_method_over_from(true, false), None);
+/// ```
+pub fn prefer_method_over_from(has_from_ctor: bool, could_be_method: bool) -> Option<String> {
+    (has_from_ctor && could_be_method).then(|| {
+        "offer a `to_`, `as_` or `into_` method on the source type, alongside or instead of the `from_` constructor, so the conversion can chain with other methods (C-CONV-SPECIFIC)"
+            .to_owned()
+    })
+}
@@ -0,0 +1,34 @@
+//! The commonly used types, for glob importing.
+//!
+//! Includes the eleven category enums, [`Guideline`], [`Category`], [`Severity`], [`GuidelineSet`]
+//! and [`Violation`]. Conversions between the category enums and [`Guideline`] go through the
+//! standard `From` and `TryFrom` traits, which need no import.
+//!
+//! ```rust
+//! use api_guidelines::prelude::*;
+//!
+//! let set: GuidelineSet = [
+//!     Guideline::from(Naming::C_CASE),
+//!     Interoperability::C_COMMON_TRAITS.into(),
+//!     Predictability::C_CTOR.into(),
+//!     Flexibility::C_GENERIC.into(),
+//!     TypeSafety::C_NEWTYPE.into(),
+//!     Dependability::C_VALIDATE.into(),
+//!     Debuggability::C_DEBUG.into(),
+//!     FutureProofing::C_SEALED.into(),
+//!     Necessities::C_STABLE.into(),
+//!     Documentation::C_EXAMPLE.into(),
+//!     Macro::C_EVOCATIVE.into(),
+//! ]
+//! .into_iter()
+//! .collect();
+//! assert_eq!(set.len(), 11);
+//! assert!(set.iter().any(|g| g.category() == Category::Macro));
+//! assert_eq!(Naming::C_CASE.severity(), Severity::Warning);
+//! ```
+
+pub use crate::{
+    Category, Debuggability, Dependability, Documentation, Flexibility, FutureProofing, Guideline,
+    GuidelineSet, Interoperability, Macro, Naming, Necessities, Predictability, Severity,
+    TypeSafety, Violation,
+};
@@ -0,0 +1,77 @@
+use crate::{Category, Guideline, Severity};
+
+/// Builder for filtered views of the catalog, following the non-consuming builder pattern of
+/// [C-BUILDER](crate::TypeSafety::C_BUILDER).
+///
+/// Filters compose: a guideline is included when it is in any of the requested categories (all
+/// of them if none is requested), at least as severe as the floor, and matches every keyword.
+///
+/// ```rust
+/// use api_guidelines::{CatalogQuery, Category, Guideline, Naming, Severity};
+///
+/// let guidelines = CatalogQuery::new()
+///     .category(Category::Naming)
+///     .category(Category::Documentation)
+///     .min_severity(Severity::Warning)
+///     .build();
+/// assert!(guidelines.contains(&Guideline::from(Naming::C_CASE)));
+/// assert!(!guidelines.contains(&Guideline::from(Naming::C_FEATURE)));
+/// assert!(guidelines.iter().all(|g| {
+///     matches!(g.category(), Category::Naming | Category::Documentation)
+///         && g.severity() >= Severity::Warning
+/// }));
+///
+/// let mut query = CatalogQuery::new();
+/// query.keyword("iterator").keyword("type");
+/// assert_eq!(query.build(), [Guideline::from(Naming::C_ITER_TY)]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CatalogQuery {
+    categories: Vec<Category>,
+    min_severity: Option<Severity>,
+    keywords: Vec<String>,
+}
+
+impl CatalogQuery {
+    /// Creates a query matching every guideline.
+    pub fn new() -> CatalogQuery {
+        CatalogQuery::default()
+    }
+
+    /// Includes the guidelines of `category`. Repeated calls include several categories.
+    pub fn category(&mut self, category: Category) -> &mut CatalogQuery {
+        if !self.categories.contains(&category) {
+            self.categories.push(category);
+        }
+        self
+    }
+
+    /// Excludes guidelines less severe than `severity`.
+    pub fn min_severity(&mut self, severity: Severity) -> &mut CatalogQuery {
+        self.min_severity = Some(severity);
+        self
+    }
+
+    /// Only includes guidelines whose code or title contains `keyword`, ignoring case.
+    pub fn keyword(&mut self, keyword: &str) -> &mut CatalogQuery {
+        self.keywords.push(keyword.to_lowercase());
+        self
+    }
+
+    /// Returns the matching guidelines in official order.
+    pub fn build(&self) -> Vec<Guideline> {
+        Guideline::all()
+            .iter()
+            .copied()
+            .filter(|g| self.categories.is_empty() || self.categories.contains(&g.category()))
+            .filter(|g| self.min_severity.is_none_or(|floor| g.severity() >= floor))
+            .filter(|g| {
+                let id = g.id().to_lowercase();
+                let title = g.title().to_lowercase();
+                self.keywords
+                    .iter()
+                    .all(|keyword| id.contains(keyword) || title.contains(keyword))
+            })
+            .collect()
+    }
+}
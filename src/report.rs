@@ -0,0 +1,113 @@
+use std::fmt::Write;
+
+use crate::{Guideline, GuidelineSet};
+
+/// Output format of a [`ReportBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Format {
+    #[default]
+    Markdown,
+    Json,
+    Text,
+}
+
+/// Builder for a compliance report over the guidelines a project has addressed, following the
+/// non-consuming builder pattern of [C-BUILDER](crate::TypeSafety::C_BUILDER).
+///
+/// Every format reports the number of addressed guidelines and the coverage percentage. Missing
+/// guidelines are listed in official order when
+/// [`include_missing`](ReportBuilder::include_missing) is set.
+///
+/// ```rust
+/// use api_guidelines::{Debuggability, Format, GuidelineSet, Naming, ReportBuilder};
+///
+/// let addressed: GuidelineSet = [Naming::C_CASE, Naming::C_CONV].into_iter().collect();
+/// let mut builder = ReportBuilder::new(&addressed);
+///
+/// let markdown = builder.render();
+/// assert!(markdown.starts_with("# API Guidelines Compliance Report\n"));
+/// assert!(markdown.contains("2/54 (3.7%)"));
+/// assert!(!markdown.contains("C-GETTER"));
+///
+/// let json = builder.format(Format::Json).include_missing(true).render();
+/// assert!(json.contains(r#""coverage":3.7"#));
+/// assert!(json.contains(r#""missing":["C-GETTER","#));
+///
+/// let text = builder.format(Format::Text).render();
+/// assert!(text.contains("2/54 (3.7%)") && text.contains("C-DEBUG"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReportBuilder {
+    addressed: GuidelineSet,
+    format: Format,
+    include_missing: bool,
+}
+
+impl ReportBuilder {
+    /// Creates a Markdown report builder for the `addressed` guidelines, without the list of
+    /// missing ones.
+    pub fn new(addressed: &GuidelineSet) -> ReportBuilder {
+        ReportBuilder {
+            addressed: *addressed,
+            format: Format::default(),
+            include_missing: false,
+        }
+    }
+
+    /// Sets the output format.
+    pub fn format(&mut self, format: Format) -> &mut ReportBuilder {
+        self.format = format;
+        self
+    }
+
+    /// Sets whether the report lists the guidelines that are not addressed.
+    pub fn include_missing(&mut self, include_missing: bool) -> &mut ReportBuilder {
+        self.include_missing = include_missing;
+        self
+    }
+
+    /// Renders the report.
+    pub fn render(&self) -> String {
+        let addressed = self.addressed.len();
+        let total = Guideline::COUNT;
+        let coverage = format!("{:.1}", addressed as f64 * 100.0 / total as f64);
+        let missing = Guideline::all()
+            .iter()
+            .filter(|&&g| self.include_missing && !self.addressed.contains(g));
+
+        let mut out = String::new();
+        match self.format {
+            Format::Markdown => {
+                out.push_str("# API Guidelines Compliance Report\n\n");
+                let _ = writeln!(out, "Coverage: {addressed}/{total} ({coverage}%)");
+                if self.include_missing {
+                    out.push_str("\n## Missing\n\n");
+                    for g in missing {
+                        let _ = writeln!(out, "- [{}]({}): {}", g.id(), g.url(), g.title());
+                    }
+                }
+            }
+            Format::Json => {
+                let _ = write!(
+                    out,
+                    r#"{{"addressed":{addressed},"total":{total},"coverage":{coverage}"#
+                );
+                if self.include_missing {
+                    let ids: Vec<String> = missing.map(|g| format!("\"{}\"", g.id())).collect();
+                    let _ = write!(out, r#","missing":[{}]"#, ids.join(","));
+                }
+                out.push_str("}\n");
+            }
+            Format::Text => {
+                let _ = writeln!(out, "Coverage: {addressed}/{total} ({coverage}%)");
+                if self.include_missing {
+                    out.push_str("Missing:\n");
+                    for g in missing {
+                        let _ = writeln!(out, "  {}: {}", g.id(), g.title());
+                    }
+                }
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::{Guideline, GuidelineSet};
+
+/// Serializes as the guideline's code, e.g. `"C-CASE"`.
+impl Serialize for Guideline {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+/// Deserializes from the guideline's code, ignoring ASCII case.
+impl<'de> Deserialize<'de> for Guideline {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Guideline, D::Error> {
+        struct CodeVisitor;
+
+        impl Visitor<'_> for CodeVisitor {
+            type Value = Guideline;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a guideline code such as \"C-CASE\"")
+            }
+
+            fn visit_str<E: de::Error>(self, code: &str) -> Result<Guideline, E> {
+                code.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CodeVisitor)
+    }
+}
+
+/// Serializes as an array of guideline codes in official order, e.g. `["C-CASE", "C-DEBUG"]`, so
+/// stored sets stay readable and don't depend on the internal layout.
+///
+/// ```rust
+/// use api_guidelines::{Debuggability, Guideline, GuidelineSet, Naming, Necessities};
+///
+/// let set: GuidelineSet = [
+///     Guideline::from(Debuggability::C_DEBUG),
+///     Naming::C_CASE.into(),
+///     Necessities::C_STABLE.into(),
+/// ]
+/// .into_iter()
+/// .collect();
+/// let json = serde_json::to_string(&set)?;
+/// assert_eq!(json, r#"["C-CASE","C-DEBUG","C-STABLE"]"#);
+/// assert_eq!(serde_json::from_str::<GuidelineSet>(&json)?, set);
+///
+/// assert!(serde_json::from_str::<GuidelineSet>(r#"["C-CASE","C-NOPE"]"#).is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+impl Serialize for GuidelineSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+/// Deserializes from an array of guideline codes, ignoring ASCII case and duplicates. Unknown
+/// codes are an error.
+impl<'de> Deserialize<'de> for GuidelineSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GuidelineSet, D::Error> {
+        struct SetVisitor;
+
+        impl<'de> Visitor<'de> for SetVisitor {
+            type Value = GuidelineSet;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence of guideline codes")
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<GuidelineSet, A::Error> {
+                let mut set = GuidelineSet::new();
+                while let Some(guideline) = seq.next_element::<Guideline>()? {
+                    set.insert(guideline);
+                }
+                Ok(set)
+            }
+        }
+
+        deserializer.deserialize_seq(SetVisitor)
+    }
+}
+
+/// Serde representation of a [`Guideline`] as an object carrying its metadata, instead of the
+/// default plain code string.
+///
+/// Serializes to `{ "category": "Naming", "id": "C-CASE", "title": "...", "url": "..." }`.
+/// Deserialization looks the guideline up by `id` and rejects a mismatched `category`; `title`
+/// and `url` are optional and not checked.
+///
+/// ```rust
+/// use api_guidelines::{Detailed, Guideline, Naming};
+///
+/// let guideline = Guideline::from(Naming::C_CASE);
+/// let json = serde_json::to_value(Detailed(guideline))?;
+/// assert_eq!(json["category"], "Naming");
+/// assert_eq!(json["id"], "C-CASE");
+/// assert_eq!(json["title"], guideline.title());
+/// assert_eq!(json["url"], guideline.url());
+///
+/// let Detailed(back) = serde_json::from_value(json)?;
+/// assert_eq!(back, guideline);
+///
+/// // The plain representation stays the default.
+/// assert_eq!(serde_json::to_string(&guideline)?, "\"C-CASE\"");
+/// assert_eq!(serde_json::from_str::<Guideline>("\"C-CASE\"")?, guideline);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Detailed(pub Guideline);
+
+impl Serialize for Detailed {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Guideline", 4)?;
+        state.serialize_field("category", self.0.category().name())?;
+        state.serialize_field("id", self.0.id())?;
+        state.serialize_field("title", self.0.title())?;
+        state.serialize_field("url", self.0.url())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Detailed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Detailed, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Guideline")]
+        struct Repr {
+            category: String,
+            id: Guideline,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        if repr.id.category().name() != repr.category {
+            return Err(de::Error::custom(format_args!(
+                "guideline '{}' is not in category {}",
+                repr.id.id(),
+                repr.category
+            )));
+        }
+        Ok(Detailed(repr.id))
+    }
+}
+
+/// Writes every guideline in official order as JSON Lines, one [`Detailed`] object per line.
+///
+/// The writer is taken by value per [C-RW-VALUE](crate::Interoperability::C_RW_VALUE); pass
+/// `&mut w` to keep using it afterwards. Wrap unbuffered writers such as files in a
+/// [`BufWriter`](std::io::BufWriter).
+///
+/// ```rust
+/// use api_guidelines::{Guideline, write_jsonl};
+///
+/// let mut out = Vec::new();
+/// write_jsonl(&mut out)?;
+/// let text = String::from_utf8(out).unwrap();
+/// let lines: Vec<&str> = text.lines().collect();
+/// assert_eq!(lines.len(), Guideline::COUNT);
+/// for line in lines {
+///     let value: serde_json::Value = serde_json::from_str(line)?;
+///     assert!(value["id"].is_string() && value["url"].is_string());
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn write_jsonl<W: Write>(mut w: W) -> io::Result<()> {
+    for &guideline in Guideline::all() {
+        serde_json::to_writer(&mut w, &Detailed(guideline))?;
+        w.write_all(b"\n")?;
+    }
+    w.flush()
+}
@@ -0,0 +1,269 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Sub;
+
+use crate::Guideline;
+
+/// A set of guidelines, for example the ones a project has reviewed or requires.
+///
+/// Iteration always yields guidelines in the order of [`Guideline::all`], regardless of
+/// insertion order.
+///
+/// ```rust
+/// use api_guidelines::{Debuggability, Guideline, GuidelineSet, Naming};
+///
+/// let mut set = GuidelineSet::new();
+/// set.insert(Debuggability::C_DEBUG);
+/// set.insert(Naming::C_CASE);
+///
+/// assert!(set.contains(Naming::C_CASE));
+/// assert_eq!(set.len(), 2);
+/// assert_eq!(
+///     set.iter().collect::<Vec<_>>(),
+///     [Guideline::from(Naming::C_CASE), Guideline::from(Debuggability::C_DEBUG)],
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GuidelineSet {
+    bits: u64,
+}
+
+// Each guideline is stored as the bit `1 << as_index()`, so the catalog must fit in `bits`.
+const _: () = assert!(Guideline::COUNT <= u64::BITS as usize);
+
+impl GuidelineSet {
+    /// Creates an empty set.
+    pub const fn new() -> GuidelineSet {
+        GuidelineSet { bits: 0 }
+    }
+
+    /// Adds a guideline to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, guideline: impl Into<Guideline>) -> bool {
+        let bit = 1 << guideline.into().as_index();
+        let inserted = self.bits & bit == 0;
+        self.bits |= bit;
+        inserted
+    }
+
+    /// Removes a guideline from the set, returning whether it was present.
+    pub fn remove(&mut self, guideline: impl Into<Guideline>) -> bool {
+        let bit = 1 << guideline.into().as_index();
+        let removed = self.bits & bit != 0;
+        self.bits &= !bit;
+        removed
+    }
+
+    /// Returns whether the guideline is in the set.
+    pub fn contains(&self, guideline: impl Into<Guideline>) -> bool {
+        self.bits & (1 << guideline.into().as_index()) != 0
+    }
+
+    /// Number of guidelines in the set.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns whether the set contains no guidelines.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Iterates over the guidelines in the set in official order.
+    pub fn iter(&self) -> Iter {
+        Iter { bits: self.bits }
+    }
+}
+
+impl Sub for GuidelineSet {
+    type Output = GuidelineSet;
+
+    /// Guidelines in `self` that are not in `rhs`.
+    fn sub(self, rhs: GuidelineSet) -> GuidelineSet {
+        GuidelineSet {
+            bits: self.bits & !rhs.bits,
+        }
+    }
+}
+
+impl Sub for &GuidelineSet {
+    type Output = GuidelineSet;
+
+    fn sub(self, rhs: &GuidelineSet) -> GuidelineSet {
+        *self - *rhs
+    }
+}
+
+impl<G: Into<Guideline>> FromIterator<G> for GuidelineSet {
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> GuidelineSet {
+        let mut set = GuidelineSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<G: Into<Guideline>> Extend<G> for GuidelineSet {
+    fn extend<I: IntoIterator<Item = G>>(&mut self, iter: I) {
+        for guideline in iter {
+            self.insert(guideline);
+        }
+    }
+}
+
+impl IntoIterator for GuidelineSet {
+    type Item = Guideline;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &GuidelineSet {
+    type Item = Guideline;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+/// Iterator over the guidelines of a [`GuidelineSet`], created by [`GuidelineSet::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter {
+    bits: u64,
+}
+
+impl Iterator for Iter {
+    type Item = Guideline;
+
+    fn next(&mut self) -> Option<Guideline> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        Some(Guideline::all()[index])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl FusedIterator for Iter {}
+
+/// Returns the guidelines of `baseline` that `addressed` does not cover, i.e.
+/// `baseline - addressed`.
+///
+/// ```rust
+/// use api_guidelines::{
+///     missing_from_baseline, Debuggability, Guideline, GuidelineSet, Interoperability, Naming,
+/// };
+///
+/// // All of Naming, plus C-DEBUG and C-SEND-SYNC.
+/// let mut baseline: GuidelineSet = Guideline::all()
+///     .iter()
+///     .filter(|g| matches!(g, Guideline::Naming(_)))
+///     .copied()
+///     .collect();
+/// baseline.insert(Debuggability::C_DEBUG);
+/// baseline.insert(Interoperability::C_SEND_SYNC);
+///
+/// // Nothing addressed yet: the whole baseline is missing.
+/// assert_eq!(missing_from_baseline(&GuidelineSet::new(), &baseline), baseline);
+///
+/// // Partial coverage reports the gaps in official order.
+/// let mut addressed: GuidelineSet = baseline.iter().skip(1).collect();
+/// addressed.remove(Debuggability::C_DEBUG);
+/// let missing = missing_from_baseline(&addressed, &baseline);
+/// assert_eq!(
+///     missing.iter().collect::<Vec<_>>(),
+///     [Guideline::from(Naming::C_CASE), Guideline::from(Debuggability::C_DEBUG)],
+/// );
+///
+/// // Full coverage, even with extra guidelines addressed, leaves nothing missing.
+/// addressed.extend(baseline);
+/// addressed.insert(Naming::C_CONV);
+/// assert!(missing_from_baseline(&addressed, &baseline).is_empty());
+/// ```
+pub fn missing_from_baseline(addressed: &GuidelineSet, baseline: &GuidelineSet) -> GuidelineSet {
+    baseline - addressed
+}
+
+/// Compares two sets, e.g. the guidelines a project reviewed in two releases.
+///
+/// ```rust
+/// use api_guidelines::{diff_sets, Debuggability, Guideline, GuidelineSet, Naming, TypeSafety};
+///
+/// let old: GuidelineSet = [Guideline::from(Naming::C_CASE), Debuggability::C_DEBUG.into()]
+///     .into_iter()
+///     .collect();
+///
+/// // Additions only.
+/// let mut new = old;
+/// new.insert(TypeSafety::C_NEWTYPE);
+/// let diff = diff_sets(&old, &new);
+/// assert_eq!(diff.added().collect::<Vec<_>>(), [Guideline::from(TypeSafety::C_NEWTYPE)]);
+/// assert_eq!(diff.removed().count(), 0);
+/// assert_eq!(diff.to_string(), "+ C-NEWTYPE\n");
+///
+/// // Removals only.
+/// let diff = diff_sets(&new, &old);
+/// assert_eq!(diff.removed().collect::<Vec<_>>(), [Guideline::from(TypeSafety::C_NEWTYPE)]);
+/// assert_eq!(diff.to_string(), "- C-NEWTYPE\n");
+///
+/// // Mixed.
+/// new.remove(Debuggability::C_DEBUG);
+/// let diff = diff_sets(&old, &new);
+/// assert_eq!(diff.to_string(), "+ C-NEWTYPE\n- C-DEBUG\n");
+///
+/// assert!(diff_sets(&old, &old).is_empty());
+/// ```
+pub fn diff_sets(old: &GuidelineSet, new: &GuidelineSet) -> SetDiff {
+    SetDiff {
+        added: new - old,
+        removed: old - new,
+    }
+}
+
+/// The difference between two sets, created by [`diff_sets`].
+///
+/// Displays as one `+ C-XXX` line per added guideline followed by one `- C-XXX` line per removed
+/// guideline, each in official order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SetDiff {
+    added: GuidelineSet,
+    removed: GuidelineSet,
+}
+
+impl SetDiff {
+    /// Guidelines in the new set but not the old one.
+    pub fn added(&self) -> Iter {
+        self.added.iter()
+    }
+
+    /// Guidelines in the old set but not the new one.
+    pub fn removed(&self) -> Iter {
+        self.removed.iter()
+    }
+
+    /// Returns whether the two sets were equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl fmt::Display for SetDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for guideline in self.added() {
+            writeln!(f, "+ {}", guideline.id())?;
+        }
+        for guideline in self.removed() {
+            writeln!(f, "- {}", guideline.id())?;
+        }
+        Ok(())
+    }
+}
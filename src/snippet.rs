@@ -0,0 +1,174 @@
+use crate::{
+    Debuggability, Dependability, FutureProofing, Guideline, Macro, Naming, Predictability,
+    TypeSafety,
+};
+
+/// A Rust example embedded in the prose of a guideline, see [`Guideline::example_snippets`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Snippet {
+    /// What the snippet demonstrates.
+    pub caption: &'static str,
+    pub code: &'static str,
+    /// Whether the snippet is meant to compile. Examples of what goes wrong are `false`.
+    pub should_compile: bool,
+}
+
+impl Guideline {
+    /// The Rust examples from the guideline's prose, completed so that the ones meant to compile
+    /// do so on their own. Empty for guidelines without such examples.
+    ///
+    /// ```rust
+    /// use api_guidelines::{FutureProofing, Guideline, Macro};
+    ///
+    /// let snippets = FutureProofing::C_SEALED.example_snippets();
+    /// assert!(!snippets.is_empty());
+    ///
+    /// for snippet in Guideline::all().iter().flat_map(|g| g.example_snippets()) {
+    ///     assert!(!snippet.caption.is_empty() && !snippet.code.trim().is_empty());
+    ///     assert!(syn::parse_file(snippet.code).is_ok());
+    /// }
+    ///
+    /// assert!(Macro::C_ANYWHERE.example_snippets().iter().any(|s| !s.should_compile));
+    /// ```
+    pub fn example_snippets(self) -> &'static [Snippet] {
+        match self {
+            Guideline::TypeSafety(TypeSafety::C_NEWTYPE) => NEWTYPE,
+            Guideline::FutureProofing(FutureProofing::C_SEALED) => SEALED,
+            Guideline::Macro(Macro::C_ANYWHERE) => ANYWHERE,
+            _ => &[],
+        }
+    }
+
+    /// The guideline whose example also documents this one, for linking to it instead of
+    /// repeating it per [C-EXAMPLE](crate::Documentation::C_EXAMPLE), or `None` when the guideline
+    /// stands on its own.
+    ///
+    /// ```rust
+    /// use api_guidelines::{Guideline, Naming};
+    ///
+    /// assert_eq!(Guideline::from(Naming::C_ITER_TY).canonical_example(), Some(Naming::C_ITER.into()));
+    /// assert_eq!(Guideline::from(Naming::C_CASE).canonical_example(), None);
+    /// ```
+    pub fn canonical_example(self) -> Option<Guideline> {
+        let target: Guideline = match self {
+            Guideline::Naming(Naming::C_ITER_TY) => Naming::C_ITER.into(),
+            Guideline::Predictability(Predictability::C_CONV_SPECIFIC) => Naming::C_CONV.into(),
+            Guideline::Dependability(Dependability::C_DTOR_BLOCK) => {
+                Dependability::C_DTOR_FAIL.into()
+            }
+            Guideline::Debuggability(Debuggability::C_DEBUG_NONEMPTY) => {
+                Debuggability::C_DEBUG.into()
+            }
+            Guideline::FutureProofing(FutureProofing::C_NEWTYPE_HIDE) => {
+                TypeSafety::C_NEWTYPE.into()
+            }
+            _ => return None,
+        };
+        Some(target)
+    }
+}
+
+const NEWTYPE: &[Snippet] = &[
+    Snippet {
+        caption: "Newtypes keep track of the intended interpretation of an f64",
+        code: "\
+struct Miles(pub f64);
+struct Kilometers(pub f64);
+
+impl Miles {
+    fn to_kilometers(self) -> Kilometers {
+        Kilometers(self.0 * 1.609344)
+    }
+}
+
+impl Kilometers {
+    fn to_miles(self) -> Miles {
+        Miles(self.0 / 1.609344)
+    }
+}
+",
+        should_compile: true,
+    },
+    Snippet {
+        caption: "A function taking Miles cannot accidentally be called with Kilometers",
+        code: "\
+struct Miles(pub f64);
+struct Kilometers(pub f64);
+
+fn are_we_there_yet(distance_travelled: Miles) -> bool {
+    distance_travelled.0 >= 42.0
+}
+
+fn main() {
+    are_we_there_yet(Kilometers(42.0));
+}
+",
+        should_compile: false,
+    },
+];
+
+const SEALED: &[Snippet] = &[Snippet {
+    caption: "The sealed trait pattern",
+    code: "\
+/// This trait is sealed and cannot be implemented for types outside this crate.
+pub trait TheTrait: private::Sealed {
+    // Zero or more methods that the user is allowed to call.
+    fn method(&self);
+
+    // Zero or more private methods, not allowed for user to call.
+    #[doc(hidden)]
+    fn private_method(&self);
+}
+
+// Implement for some types.
+impl TheTrait for usize {
+    fn method(&self) {}
+    fn private_method(&self) {}
+}
+
+mod private {
+    pub trait Sealed {}
+
+    // Implement for those same types, but no others.
+    impl Sealed for usize {}
+}
+",
+    should_compile: true,
+}];
+
+const ANYWHERE: &[Snippet] = &[
+    Snippet {
+        caption: "A macro that works in module scope",
+        code: "\
+macro_rules! broken {
+    ($m:ident :: $t:ident) => {
+        pub struct $t;
+        pub mod $m {
+            pub use super::$t;
+        }
+    }
+}
+
+broken!(m::T); // okay, expands to T and m::T
+",
+        should_compile: true,
+    },
+    Snippet {
+        caption: "The same macro fails in function scope",
+        code: "\
+macro_rules! broken {
+    ($m:ident :: $t:ident) => {
+        pub struct $t;
+        pub mod $m {
+            pub use super::$t;
+        }
+    }
+}
+
+fn g() {
+    broken!(m::U); // fails to compile, super::U refers to the containing module not g
+}
+",
+        should_compile: false,
+    },
+];
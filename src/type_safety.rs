@@ -0,0 +1,82 @@
+//! Helpers for applying the [`TypeSafety`] guidelines.
+
+pub use crate::TypeSafety;
+
+/// Suggests the [`bitflags`](https://github.com/bitflags/bitflags) crate for a type whose values
+/// are meant to be OR-combined, per [C-BITFLAG](crate::TypeSafety::C_BITFLAG).
+///
+/// An enum is the right choice when the API requests exactly one choice among many; no suggestion
+/// is made then. For flags, the hint names the smallest integer type with one bit per flag.
+///
+/// ```rust
+/// use api_guidelines::type_safety::should_use_bitflags;
+///
+/// let hint = should_use_bitflags(true, 3).unwrap();
+/// assert!(hint.contains("bitflags") && hint.contains("u8"));
+///
+/// assert_eq!(should_use_bitflags(false, 3), None);
+/// ```
+pub fn should_use_bitflags(is_set_of_flags: bool, variant_count: usize) -> Option<String> {
+    if !is_set_of_flags {
+        return None;
+    }
+    let hint = match variant_count {
+        0..=8 => flags_hint("u8"),
+        9..=16 => flags_hint("u16"),
+        17..=32 => flags_hint("u32"),
+        33..=64 => flags_hint("u64"),
+        65..=128 => flags_hint("u128"),
+        _ => "a set of more than 128 flags does not fit in an integer; use a set type instead of an enum".to_owned(),
+    };
+    Some(hint)
+}
+
+fn flags_hint(repr: &str) -> String {
+    format!(
+        "represent the flags with the `bitflags` crate, e.g. `bitflags! {{ struct Flags: {repr} {{ ... }} }}`, instead of an enum"
+    )
+}
+
+/// Flags parameters whose meaning is unclear at the call site, per
+/// [C-CUSTOM-TYPE](crate::TypeSafety::C_CUSTOM_TYPE), returning one suggestion per parameter.
+///
+/// `bool`, bare `Option<T>` and `u8` parameters have many possible interpretations. A `bool` next
+/// to another `bool` is called out in particular, since `Widget::new(true, false)` is easy to get
+/// backwards.
+///
+/// ```rust
+/// use api_guidelines::type_safety::suggest_custom_type;
+///
+/// let suggestions = suggest_custom_type(&["bool", "bool"]);
+/// assert_eq!(suggestions.len(), 2);
+/// assert!(suggestions.iter().all(|s| s.contains("newtype") && s.contains("adjacent")));
+///
+/// assert!(suggest_custom_type(&["Size", "Shape"]).is_empty());
+/// ```
+pub fn suggest_custom_type(param_types: &[&str]) -> Vec<String> {
+    let is_bool = |i: usize| param_types.get(i).is_some_and(|ty| ty.trim() == "bool");
+    param_types
+        .iter()
+        .enumerate()
+        .filter_map(|(i, ty)| {
+            let ty = ty.trim();
+            let what = if ty == "bool" {
+                "a dedicated enum such as `Small`/`Large`"
+            } else if ty.starts_with("Option<") {
+                "an enum naming what the absent case means"
+            } else if ty == "u8" {
+                "a type stating what the number stands for"
+            } else {
+                return None;
+            };
+            let mut suggestion = format!(
+                "parameter {} (`{ty}`): use {what}, or wrap it with the newtype pattern (C-NEWTYPE)",
+                i + 1
+            );
+            if ty == "bool" && ((i > 0 && is_bool(i - 1)) || is_bool(i + 1)) {
+                suggestion.push_str("; it is adjacent to another `bool`, so call sites are easy to mix up");
+            }
+            Some(suggestion)
+        })
+        .collect()
+}
@@ -0,0 +1,62 @@
+use std::fmt;
+
+use crate::Guideline;
+
+/// A place where code breaks a guideline, the common result type of the checkers.
+///
+/// Displays as `[C-XXX] message`. Checkers that report richer findings, such as
+/// [`DerefLint`](crate::predictability::DerefLint), convert into it with `From`.
+///
+/// ```rust
+/// use api_guidelines::naming::{lint_identifier, ItemKind};
+/// use api_guidelines::predictability::check_deref_impl;
+/// use api_guidelines::Violation;
+///
+/// let violation = lint_identifier("UUID", ItemKind::Struct).unwrap();
+/// assert_eq!(violation.to_string(), "[C-CASE] UUID: prefer Uuid");
+/// assert_eq!(violation.suggestion.as_deref(), Some("Uuid"));
+///
+/// let violation = Violation::from(check_deref_impl(false, "Config").unwrap());
+/// assert!(violation.to_string().starts_with("[C-DEREF] `Config` implements Deref"));
+/// assert!(!violation.message.ends_with("(C-DEREF)"));
+/// assert_eq!(violation.span_hint.as_deref(), Some("Config"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Violation {
+    pub guideline: Guideline,
+    pub message: String,
+    /// The replacement to offer as a fix, if there is a mechanical one.
+    pub suggestion: Option<String>,
+    /// What the violation points at, such as an identifier or a line, for locating it in the
+    /// source.
+    pub span_hint: Option<String>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.guideline.id(), self.message)
+    }
+}
+
+impl Violation {
+    /// Builds a violation from a checker message, dropping the trailing ` (C-XXX)` code since
+    /// `Display` already shows it.
+    pub(crate) fn from_message(
+        guideline: impl Into<Guideline>,
+        message: &str,
+        span_hint: Option<String>,
+    ) -> Violation {
+        let guideline = guideline.into();
+        let message = message
+            .strip_suffix(')')
+            .and_then(|rest| rest.strip_suffix(guideline.id()))
+            .and_then(|rest| rest.strip_suffix(" ("))
+            .unwrap_or(message);
+        Violation {
+            guideline,
+            message: message.to_owned(),
+            suggestion: None,
+            span_hint,
+        }
+    }
+}